@@ -5,7 +5,11 @@
  * // license that can be found in the LICENSE file.
  */
 use criterion::{Criterion, criterion_group, criterion_main};
-use mm3h::{murmurhash2_64, murmurhash3_32, murmurhash3_128};
+use mm3h::{
+    Murmur3Hasher, murmurhash2_64, murmurhash3_32, murmurhash3_32_batch, murmurhash3_128,
+    murmurhash3_x86_128,
+};
+use std::hash::{Hash, Hasher};
 
 pub fn criterion_benchmark(c: &mut Criterion) {
     c.benchmark_group("Hash");
@@ -45,6 +49,31 @@ The current version, completed April 3, 2011, is MurmurHash3,[12][13] which yiel
         })
     });
 
+    let keys: Vec<&[u8]> = v.chunks(24).collect();
+    let mut out = vec![0u32; keys.len()];
+
+    c.bench_function("murmurhash32_keys_single", |b| {
+        b.iter(|| {
+            for (key, dst) in keys.iter().zip(out.iter_mut()) {
+                *dst = murmurhash3_32(key);
+            }
+        })
+    });
+
+    c.bench_function("murmurhash32_keys_batch", |b| {
+        b.iter(|| {
+            murmurhash3_32_batch(&keys, 0, &mut out);
+        })
+    });
+
+    c.bench_function("murmur3_hasher_tuple_key", |b| {
+        b.iter(|| {
+            let mut hasher = Murmur3Hasher::new();
+            std::hint::black_box((0x0123_4567_89ab_cdefu64, 0x89ab_cdefu32)).hash(&mut hasher);
+            hasher.finish()
+        })
+    });
+
     c.bench_function("murmurhash64a", |b| {
         b.iter(|| {
             _ = murmurhash2_64(&v);
@@ -56,6 +85,12 @@ The current version, completed April 3, 2011, is MurmurHash3,[12][13] which yiel
             _ = murmurhash3_128(&v);
         })
     });
+
+    c.bench_function("murmurhash3_x86_128", |b| {
+        b.iter(|| {
+            _ = murmurhash3_x86_128(&v);
+        })
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
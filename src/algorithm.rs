@@ -0,0 +1,341 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 10/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::buffer::InlineBuffer;
+use crate::generic64::Murmur64AHasher;
+use crate::generic128::Murmur3x64_128;
+use crate::generic128_x86::Murmur3x86_128;
+use crate::hasher::{Murmur2AHasher, Murmur3Hasher};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+/// A Murmur hash algorithm, so code can take the algorithm as a type parameter.
+///
+/// Every algorithm of the crate has a zero-sized marker type implementing
+/// this trait, e.g. [`MurmurHash3_32`] or [`MurmurHash2_64`].
+///
+/// ```
+/// use mm3h::{MurmurHash, MurmurHash3_128, MurmurStream};
+///
+/// fn fingerprint<A: MurmurHash>(parts: &[&[u8]], seed: A::Seed) -> A::Output {
+///     let mut stream = A::new_stream(seed);
+///     for part in parts {
+///         stream.update(part);
+///     }
+///     stream.finalize()
+/// }
+///
+/// assert_eq!(
+///     fingerprint::<MurmurHash3_128>(&[b"hello ", b"world"], 42),
+///     MurmurHash3_128::hash(b"hello world", 42),
+/// );
+/// ```
+pub trait MurmurHash {
+    /// The seed type taken by the algorithm.
+    type Seed: Copy + Default + fmt::Debug;
+    /// The hash value produced by the algorithm.
+    type Output: Copy + Eq + Hash + fmt::Debug;
+    /// The incremental hasher returned by [`MurmurHash::new_stream`].
+    type Stream: MurmurStream<Output = Self::Output>;
+
+    /// Hashes `bytes` with `seed` in one shot.
+    fn hash(bytes: &[u8], seed: Self::Seed) -> Self::Output;
+
+    /// Creates a stream whose [`MurmurStream::finalize`] equals
+    /// [`MurmurHash::hash`] over all bytes passed to [`MurmurStream::update`].
+    fn new_stream(seed: Self::Seed) -> Self::Stream;
+}
+
+/// Incremental input to a [`MurmurHash`] algorithm.
+pub trait MurmurStream {
+    /// The hash value produced by the stream.
+    type Output;
+
+    /// Feeds the next chunk of input.
+    fn update(&mut self, bytes: &[u8]);
+
+    /// Returns the hash of all input fed so far, without consuming the state.
+    fn finalize(&self) -> Self::Output;
+}
+
+/// A [`MurmurStream`] for algorithms that can only hash the complete input.
+///
+/// MurmurHash1, MurmurHash2, MurmurHashNeutral2, MurmurHashAligned2 and
+/// MurmurHash64B mix the total length into their initial state, so no block
+/// can be mixed before the input is complete. This stream collects all input
+/// and hashes it with [`MurmurHash::hash`] on [`MurmurStream::finalize`]:
+/// up to 64 bytes are kept inline, **longer input is copied to a heap
+/// allocation** that grows with it.
+pub struct BufferedStream<A: MurmurHash> {
+    seed: A::Seed,
+    buffer: InlineBuffer,
+    algorithm: PhantomData<A>,
+}
+
+impl<A: MurmurHash> BufferedStream<A> {
+    /// Creates an empty stream hashing with `seed`.
+    pub fn new(seed: A::Seed) -> Self {
+        Self {
+            seed,
+            buffer: InlineBuffer::new(),
+            algorithm: PhantomData,
+        }
+    }
+}
+
+impl<A: MurmurHash> Clone for BufferedStream<A> {
+    fn clone(&self) -> Self {
+        Self {
+            seed: self.seed,
+            buffer: self.buffer.clone(),
+            algorithm: PhantomData,
+        }
+    }
+}
+
+impl<A: MurmurHash> fmt::Debug for BufferedStream<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BufferedStream")
+            .field("seed", &self.seed)
+            .field("len", &self.buffer.as_slice().len())
+            .finish()
+    }
+}
+
+impl<A: MurmurHash> MurmurStream for BufferedStream<A> {
+    type Output = A::Output;
+
+    fn update(&mut self, bytes: &[u8]) {
+        self.buffer.extend(bytes);
+    }
+
+    fn finalize(&self) -> A::Output {
+        A::hash(self.buffer.as_slice(), self.seed)
+    }
+}
+
+impl MurmurStream for Murmur3Hasher {
+    type Output = u32;
+
+    fn update(&mut self, bytes: &[u8]) {
+        self.write(bytes);
+    }
+
+    fn finalize(&self) -> u32 {
+        self.finish_32()
+    }
+}
+
+impl MurmurStream for Murmur2AHasher {
+    type Output = u32;
+
+    fn update(&mut self, bytes: &[u8]) {
+        self.add(bytes);
+    }
+
+    fn finalize(&self) -> u32 {
+        self.end()
+    }
+}
+
+impl MurmurStream for Murmur64AHasher {
+    type Output = u64;
+
+    fn update(&mut self, bytes: &[u8]) {
+        self.write(bytes);
+    }
+
+    fn finalize(&self) -> u64 {
+        self.finish()
+    }
+}
+
+impl MurmurStream for Murmur3x64_128 {
+    type Output = u128;
+
+    fn update(&mut self, bytes: &[u8]) {
+        Murmur3x64_128::update(self, bytes);
+    }
+
+    fn finalize(&self) -> u128 {
+        Murmur3x64_128::finalize(self)
+    }
+}
+
+impl MurmurStream for Murmur3x86_128 {
+    type Output = u128;
+
+    fn update(&mut self, bytes: &[u8]) {
+        Murmur3x86_128::update(self, bytes);
+    }
+
+    fn finalize(&self) -> u128 {
+        Murmur3x86_128::finalize(self)
+    }
+}
+
+macro_rules! murmur_algorithm {
+    (
+        $(#[$doc:meta])*
+        $name:ident, $seed:ty, $output:ty, $hash:path,
+        $stream:ty, $new_stream:expr
+    ) => {
+        $(#[$doc])*
+        #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+        pub struct $name;
+
+        impl MurmurHash for $name {
+            type Seed = $seed;
+            type Output = $output;
+            type Stream = $stream;
+
+            #[inline]
+            fn hash(bytes: &[u8], seed: $seed) -> $output {
+                $hash(bytes, seed)
+            }
+
+            #[inline]
+            fn new_stream(seed: $seed) -> $stream {
+                $new_stream(seed)
+            }
+        }
+    };
+}
+
+murmur_algorithm!(
+    /// **MurmurHash1**, see [`murmurhash1_32_with_seed`](crate::murmurhash1_32_with_seed).
+    ///
+    /// Its stream collects all input, see [`BufferedStream`].
+    MurmurHash1_32, u32, u32, crate::murmurhash1_32_with_seed,
+    BufferedStream<Self>, BufferedStream::new
+);
+murmur_algorithm!(
+    /// **MurmurHash2**, see [`murmurhash2_32_with_seed`](crate::murmurhash2_32_with_seed).
+    ///
+    /// Its stream collects all input, see [`BufferedStream`].
+    MurmurHash2_32, u32, u32, crate::murmurhash2_32_with_seed,
+    BufferedStream<Self>, BufferedStream::new
+);
+murmur_algorithm!(
+    /// **MurmurHash2A**, see [`murmurhash2a_32_with_seed`](crate::murmurhash2a_32_with_seed).
+    MurmurHash2A, u32, u32, crate::murmurhash2a_32_with_seed,
+    Murmur2AHasher, Murmur2AHasher::new_with_seed
+);
+murmur_algorithm!(
+    /// **MurmurHashNeutral2**, see [`murmurhash_neutral2_with_seed`](crate::murmurhash_neutral2_with_seed).
+    ///
+    /// Its stream collects all input, see [`BufferedStream`].
+    MurmurHashNeutral2, u32, u32, crate::murmurhash_neutral2_with_seed,
+    BufferedStream<Self>, BufferedStream::new
+);
+murmur_algorithm!(
+    /// **MurmurHashAligned2**, see [`murmurhash_aligned2_with_seed`](crate::murmurhash_aligned2_with_seed).
+    ///
+    /// Its stream collects all input, see [`BufferedStream`].
+    MurmurHashAligned2, u32, u32, crate::murmurhash_aligned2_with_seed,
+    BufferedStream<Self>, BufferedStream::new
+);
+murmur_algorithm!(
+    /// **MurmurHash64A**, see [`murmurhash2_64_with_seed`](crate::murmurhash2_64_with_seed).
+    ///
+    /// Its stream collects all input, see [`Murmur64AHasher`].
+    MurmurHash2_64, u64, u64, crate::murmurhash2_64_with_seed,
+    Murmur64AHasher, Murmur64AHasher::new_with_seed
+);
+murmur_algorithm!(
+    /// **MurmurHash64B**, see [`murmurhash2_64b_with_seed`](crate::murmurhash2_64b_with_seed).
+    ///
+    /// Its stream collects all input, see [`BufferedStream`].
+    MurmurHash2_64B, u64, u64, crate::murmurhash2_64b_with_seed,
+    BufferedStream<Self>, BufferedStream::new
+);
+murmur_algorithm!(
+    /// **MurmurHash3 (x86_32)**, see [`murmurhash3_32_with_seed`](crate::murmurhash3_32_with_seed).
+    MurmurHash3_32, u32, u32, crate::murmurhash3_32_with_seed,
+    Murmur3Hasher, Murmur3Hasher::new_with_seed
+);
+murmur_algorithm!(
+    /// **MurmurHash3 (x86_128)**, see [`murmurhash3_x86_128_with_seed`](crate::murmurhash3_x86_128_with_seed).
+    MurmurHash3X86_128, u32, u128, crate::murmurhash3_x86_128_with_seed,
+    Murmur3x86_128, Murmur3x86_128::new_with_seed
+);
+murmur_algorithm!(
+    /// **MurmurHash3 (x64_128)**, see [`murmurhash3_128_with_seed`](crate::murmurhash3_128_with_seed).
+    MurmurHash3_128, u32, u128, crate::murmurhash3_128_with_seed,
+    Murmur3x64_128, Murmur3x64_128::new_with_seed
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn check<A: MurmurHash>(seed: A::Seed) {
+        let data: Vec<u8> = (0..200u32).map(|x| (x * 13 + 7) as u8).collect();
+        for len in [0usize, 1, 3, 4, 7, 8, 15, 16, 17, 63, 64, 65, 200] {
+            let expected = A::hash(&data[..len], seed);
+            for split in [1usize, 3, 5, 16] {
+                let mut stream = A::new_stream(seed);
+                for chunk in data[..len].chunks(split) {
+                    stream.update(chunk);
+                }
+                assert_eq!(stream.finalize(), expected, "length {len}, split {split}");
+            }
+        }
+    }
+
+    #[test]
+    fn streams_match_one_shot() {
+        check::<MurmurHash1_32>(0x9747b28c);
+        check::<MurmurHash2_32>(0x9747b28c);
+        check::<MurmurHash2A>(0x9747b28c);
+        check::<MurmurHashNeutral2>(0x9747b28c);
+        check::<MurmurHashAligned2>(0x9747b28c);
+        check::<MurmurHash2_64>(0x9747_b28c_dead_beef);
+        check::<MurmurHash2_64B>(0x9747_b28c_dead_beef);
+        check::<MurmurHash3_32>(0x9747b28c);
+        check::<MurmurHash3X86_128>(0x9747b28c);
+        check::<MurmurHash3_128>(0x9747b28c);
+    }
+
+    #[test]
+    fn markers_use_the_free_functions() {
+        assert_eq!(
+            MurmurHash3_32::hash(b"hello", 1),
+            crate::murmurhash3_32_with_seed(b"hello", 1)
+        );
+        assert_eq!(
+            MurmurHash2_64::hash(b"hello", 1),
+            crate::murmurhash2_64_with_seed(b"hello", 1)
+        );
+        assert_eq!(
+            MurmurHash3_128::hash(b"hello", 1),
+            crate::murmurhash3_128_with_seed(b"hello", 1)
+        );
+    }
+}
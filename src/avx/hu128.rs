@@ -0,0 +1,193 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 10/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::generic128_x86::{C1, C2, C3, C4, murmurhash3_x86_128_round};
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn _mm_rotate_lanes(v: __m128i, left: __m128i, right: __m128i) -> __m128i {
+    _mm_or_si128(_mm_sllv_epi32(v, left), _mm_srlv_epi32(v, right))
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn _mm256_rotate_lanes(v: __m256i, left: __m256i, right: __m256i) -> __m256i {
+    _mm256_or_si256(_mm256_sllv_epi32(v, left), _mm256_srlv_epi32(v, right))
+}
+
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn avx_murmurhash3_x86_128_blocks(bytes: &[u8], h: [u32; 4]) -> [u32; 4] {
+    let mut h = h;
+
+    let m1 = _mm256_setr_epi32(
+        C1 as i32, C2 as i32, C3 as i32, C4 as i32, C1 as i32, C2 as i32, C3 as i32, C4 as i32,
+    );
+    let m2 = _mm256_setr_epi32(
+        C2 as i32, C3 as i32, C4 as i32, C1 as i32, C2 as i32, C3 as i32, C4 as i32, C1 as i32,
+    );
+    let left = _mm256_setr_epi32(15, 16, 17, 18, 15, 16, 17, 18);
+    let right = _mm256_setr_epi32(17, 16, 15, 14, 17, 16, 15, 14);
+
+    for chunk in bytes.chunks_exact(64) {
+        unsafe {
+            let mut ak1 = _mm256_loadu_si256(chunk.as_ptr().cast());
+            let mut ak2 = _mm256_loadu_si256(chunk.get_unchecked(32..).as_ptr().cast());
+
+            ak1 = _mm256_mullo_epi32(ak1, m1);
+            ak2 = _mm256_mullo_epi32(ak2, m1);
+
+            ak1 = _mm256_rotate_lanes(ak1, left, right);
+            ak2 = _mm256_rotate_lanes(ak2, left, right);
+
+            ak1 = _mm256_mullo_epi32(ak1, m2);
+            ak2 = _mm256_mullo_epi32(ak2, m2);
+
+            h = murmurhash3_x86_128_round(
+                h,
+                [
+                    _mm256_extract_epi32::<0>(ak1) as u32,
+                    _mm256_extract_epi32::<1>(ak1) as u32,
+                    _mm256_extract_epi32::<2>(ak1) as u32,
+                    _mm256_extract_epi32::<3>(ak1) as u32,
+                ],
+            );
+            h = murmurhash3_x86_128_round(
+                h,
+                [
+                    _mm256_extract_epi32::<4>(ak1) as u32,
+                    _mm256_extract_epi32::<5>(ak1) as u32,
+                    _mm256_extract_epi32::<6>(ak1) as u32,
+                    _mm256_extract_epi32::<7>(ak1) as u32,
+                ],
+            );
+            h = murmurhash3_x86_128_round(
+                h,
+                [
+                    _mm256_extract_epi32::<0>(ak2) as u32,
+                    _mm256_extract_epi32::<1>(ak2) as u32,
+                    _mm256_extract_epi32::<2>(ak2) as u32,
+                    _mm256_extract_epi32::<3>(ak2) as u32,
+                ],
+            );
+            h = murmurhash3_x86_128_round(
+                h,
+                [
+                    _mm256_extract_epi32::<4>(ak2) as u32,
+                    _mm256_extract_epi32::<5>(ak2) as u32,
+                    _mm256_extract_epi32::<6>(ak2) as u32,
+                    _mm256_extract_epi32::<7>(ak2) as u32,
+                ],
+            );
+        }
+    }
+
+    let rem = bytes.chunks_exact(64).remainder();
+
+    for chunk in rem.chunks_exact(32) {
+        unsafe {
+            let mut k1 = _mm256_loadu_si256(chunk.as_ptr().cast());
+
+            k1 = _mm256_mullo_epi32(k1, m1);
+            k1 = _mm256_rotate_lanes(k1, left, right);
+            k1 = _mm256_mullo_epi32(k1, m2);
+
+            h = murmurhash3_x86_128_round(
+                h,
+                [
+                    _mm256_extract_epi32::<0>(k1) as u32,
+                    _mm256_extract_epi32::<1>(k1) as u32,
+                    _mm256_extract_epi32::<2>(k1) as u32,
+                    _mm256_extract_epi32::<3>(k1) as u32,
+                ],
+            );
+            h = murmurhash3_x86_128_round(
+                h,
+                [
+                    _mm256_extract_epi32::<4>(k1) as u32,
+                    _mm256_extract_epi32::<5>(k1) as u32,
+                    _mm256_extract_epi32::<6>(k1) as u32,
+                    _mm256_extract_epi32::<7>(k1) as u32,
+                ],
+            );
+        }
+    }
+
+    let rem = rem.chunks_exact(32).remainder();
+
+    for chunk in rem.chunks_exact(16) {
+        unsafe {
+            let mut k1 = _mm_loadu_si128(chunk.as_ptr().cast());
+
+            k1 = _mm_mullo_epi32(k1, _mm256_castsi256_si128(m1));
+            k1 = _mm_rotate_lanes(
+                k1,
+                _mm256_castsi256_si128(left),
+                _mm256_castsi256_si128(right),
+            );
+            k1 = _mm_mullo_epi32(k1, _mm256_castsi256_si128(m2));
+
+            h = murmurhash3_x86_128_round(
+                h,
+                [
+                    _mm_extract_epi32::<0>(k1) as u32,
+                    _mm_extract_epi32::<1>(k1) as u32,
+                    _mm_extract_epi32::<2>(k1) as u32,
+                    _mm_extract_epi32::<3>(k1) as u32,
+                ],
+            );
+        }
+    }
+
+    h
+}
+
+#[cfg(test)]
+mod test {
+    use super::avx_murmurhash3_x86_128_blocks;
+    use crate::generic128_x86::murmurhash3_x86_128_blocks;
+
+    #[test]
+    fn test_matches_generic() {
+        if std::arch::is_x86_feature_detected!("avx2") {
+            let data: Vec<u8> = (0..300u32).map(|x| (x * 17 + 3) as u8).collect();
+            for len in 0..data.len() {
+                let seed = [len as u32, 1, 2, 0x9747b28c];
+                unsafe {
+                    assert_eq!(
+                        avx_murmurhash3_x86_128_blocks(&data[..len], seed),
+                        murmurhash3_x86_128_blocks(&data[..len], seed)
+                    );
+                }
+            }
+        }
+    }
+}
@@ -26,7 +26,10 @@
  * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
-use crate::generic::scramble;
+use crate::generic::murmurhash3_32_finalize;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
 #[inline]
@@ -47,14 +50,16 @@ unsafe fn _mm256_rotate_left15(v: __m256i) -> __m256i {
 
 #[target_feature(enable = "avx2")]
 pub(crate) unsafe fn avx_murmurhash3_32(bytes: &[u8], seed: u32) -> u32 {
+    let h1 = unsafe { avx_murmurhash3_32_blocks(bytes, seed) };
+    murmurhash3_32_finalize(bytes.chunks_exact(4).remainder(), h1, bytes.len())
+}
+
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn avx_murmurhash3_32_blocks(bytes: &[u8], h1: u32) -> u32 {
     const C1: u32 = 0xcc9e2d51u32;
     const C2: u32 = 0x1b873593u32;
 
-    let read_size = 4;
-    let len = bytes.len() as u32;
-    let block_count = len / read_size;
-
-    let mut h1 = seed;
+    let mut h1 = h1;
 
     let c1 = _mm256_set1_epi32(C1 as i32);
     let c2 = _mm256_set1_epi32(C2 as i32);
@@ -212,7 +217,7 @@ pub(crate) unsafe fn avx_murmurhash3_32(bytes: &[u8], seed: u32) -> u32 {
     let rem = rem.chunks_exact(32).remainder();
 
     for chunk in rem.chunks_exact(4) {
-        let mut k1 = u32::from_ne_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]).to_le();
+        let mut k1 = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
 
         k1 = k1.wrapping_mul(C1);
         k1 = k1.rotate_left(15);
@@ -224,25 +229,6 @@ pub(crate) unsafe fn avx_murmurhash3_32(bytes: &[u8], seed: u32) -> u32 {
         h1 = h1.wrapping_add(0xe6546b64)
     }
 
-    let mut k1 = 0u32;
-
-    if len & 3 == 3 {
-        k1 ^= (bytes[(block_count * read_size) as usize + 2] as u32) << 16;
-    }
-    if len & 3 >= 2 {
-        k1 ^= (bytes[(block_count * read_size) as usize + 1] as u32) << 8;
-    }
-    if len & 3 >= 1 {
-        k1 ^= bytes[(block_count * read_size) as usize] as u32;
-        k1 = k1.wrapping_mul(C1);
-        k1 = k1.rotate_left(15);
-        k1 = k1.wrapping_mul(C2);
-        h1 ^= k1;
-    }
-
-    h1 ^= bytes.len() as u32;
-    h1 = scramble(h1);
-
     h1
 }
 
@@ -0,0 +1,145 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 10/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::batch::{
+    hash_lane_groups, lane_active, lane_blocks, lane_lens, lane_tails, lane_words,
+    lane_words_unchecked,
+};
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn _mm256_loadu_lanes(v: &[u32; 8]) -> __m256i {
+    unsafe { _mm256_loadu_si256(v.as_ptr().cast()) }
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn _mm256_mix_k1(k1: __m256i, c1: __m256i, c2: __m256i) -> __m256i {
+    let k1 = _mm256_mullo_epi32(k1, c1);
+    let k1 = _mm256_or_si256(_mm256_slli_epi32::<15>(k1), _mm256_srli_epi32::<17>(k1));
+    _mm256_mullo_epi32(k1, c2)
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn _mm256_mix_h1(h1: __m256i, k1: __m256i) -> __m256i {
+    let h1 = _mm256_xor_si256(h1, k1);
+    let h1 = _mm256_or_si256(_mm256_slli_epi32::<13>(h1), _mm256_srli_epi32::<19>(h1));
+    // h1 * 5
+    let h1 = _mm256_add_epi32(_mm256_slli_epi32::<2>(h1), h1);
+    _mm256_add_epi32(h1, _mm256_set1_epi32(0xe6546b64u32 as i32))
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn avx_murmurhash3_32_batch8(keys: &[&[u8]; 8], seed: u32) -> [u32; 8] {
+    const C1: u32 = 0xcc9e2d51u32;
+    const C2: u32 = 0x1b873593u32;
+
+    let c1 = _mm256_set1_epi32(C1 as i32);
+    let c2 = _mm256_set1_epi32(C2 as i32);
+
+    let blocks = lane_blocks(keys);
+    let min_blocks = blocks.iter().copied().min().unwrap_or(0);
+    let max_blocks = blocks.iter().copied().max().unwrap_or(0);
+
+    let mut h1 = _mm256_set1_epi32(seed as i32);
+
+    unsafe {
+        for i in 0..min_blocks {
+            let k1 = _mm256_loadu_lanes(&lane_words_unchecked(keys, i));
+            h1 = _mm256_mix_h1(h1, _mm256_mix_k1(k1, c1, c2));
+        }
+
+        for i in min_blocks..max_blocks {
+            let active = _mm256_loadu_lanes(&lane_active(&blocks, i));
+            let k1 = _mm256_loadu_lanes(&lane_words(keys, i));
+            let mixed = _mm256_mix_h1(h1, _mm256_mix_k1(k1, c1, c2));
+            h1 = _mm256_blendv_epi8(h1, mixed, active);
+        }
+
+        // A zero tail word mixes to zero, so lanes without a tail are untouched.
+        let tail = _mm256_loadu_lanes(&lane_tails(keys));
+        h1 = _mm256_xor_si256(h1, _mm256_mix_k1(tail, c1, c2));
+
+        h1 = _mm256_xor_si256(h1, _mm256_loadu_lanes(&lane_lens(keys)));
+    }
+
+    h1 = _mm256_xor_si256(h1, _mm256_srli_epi32::<16>(h1));
+    h1 = _mm256_mullo_epi32(h1, _mm256_set1_epi32(0x85ebca6bu32 as i32));
+    h1 = _mm256_xor_si256(h1, _mm256_srli_epi32::<13>(h1));
+    h1 = _mm256_mullo_epi32(h1, _mm256_set1_epi32(0xc2b2ae35u32 as i32));
+    h1 = _mm256_xor_si256(h1, _mm256_srli_epi32::<16>(h1));
+
+    let mut out = [0u32; 8];
+    unsafe { _mm256_storeu_si256(out.as_mut_ptr().cast(), h1) };
+    out
+}
+
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn avx_murmurhash3_32_batch(keys: &[&[u8]], seed: u32, out: &mut [u32]) {
+    hash_lane_groups(keys.iter().copied(), out, |group| unsafe {
+        avx_murmurhash3_32_batch8(group, seed)
+    });
+}
+
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn avx_murmurhash3_32_batch_fixed(
+    keys: &[u8],
+    key_len: usize,
+    seed: u32,
+    out: &mut [u32],
+) {
+    hash_lane_groups(keys.chunks_exact(key_len), out, |group| unsafe {
+        avx_murmurhash3_32_batch8(group, seed)
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::avx_murmurhash3_32_batch;
+    use crate::generic::murmurhash3_32;
+
+    #[test]
+    fn test_matches_generic() {
+        if std::arch::is_x86_feature_detected!("avx2") {
+            let owned: Vec<Vec<u8>> = (0..131u32)
+                .map(|i| (0..(i * 5) % 67).map(|x| (x * 17 + i) as u8).collect())
+                .collect();
+            let keys: Vec<&[u8]> = owned.iter().map(|k| k.as_slice()).collect();
+            let expected: Vec<u32> = keys.iter().map(|k| murmurhash3_32(k, 7)).collect();
+
+            let mut out = vec![0u32; keys.len()];
+            unsafe { avx_murmurhash3_32_batch(&keys, 7, &mut out) };
+            assert_eq!(out, expected);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 10/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::generic::murmurhash3_32_finalize;
+use std::arch::x86_64::*;
+
+#[target_feature(enable = "avx512f")]
+pub(crate) unsafe fn avx512_murmurhash3_32(bytes: &[u8], seed: u32) -> u32 {
+    let h1 = unsafe { avx512_murmurhash3_32_blocks(bytes, seed) };
+    murmurhash3_32_finalize(bytes.chunks_exact(4).remainder(), h1, bytes.len())
+}
+
+#[target_feature(enable = "avx512f")]
+pub(crate) unsafe fn avx512_murmurhash3_32_blocks(bytes: &[u8], h1: u32) -> u32 {
+    const C1: u32 = 0xcc9e2d51u32;
+    const C2: u32 = 0x1b873593u32;
+
+    let mut h1 = h1;
+
+    let c1 = _mm512_set1_epi32(C1 as i32);
+    let c2 = _mm512_set1_epi32(C2 as i32);
+
+    let mut lanes = [0u32; 16];
+
+    for chunk in bytes.chunks_exact(64) {
+        unsafe {
+            let mut k1 = _mm512_loadu_si512(chunk.as_ptr().cast());
+
+            k1 = _mm512_mullo_epi32(k1, c1);
+            k1 = _mm512_rol_epi32::<15>(k1);
+            k1 = _mm512_mullo_epi32(k1, c2);
+
+            _mm512_storeu_si512(lanes.as_mut_ptr().cast(), k1);
+        }
+
+        for k1 in lanes {
+            h1 ^= k1;
+            h1 = h1.rotate_left(13);
+            h1 = h1.wrapping_mul(5);
+            h1 = h1.wrapping_add(0xe6546b64);
+        }
+    }
+
+    let rem = bytes.chunks_exact(64).remainder();
+    let blocks = rem.len() / 4;
+
+    if blocks != 0 {
+        unsafe {
+            let mask = ((1u32 << blocks) - 1) as __mmask16;
+            let mut k1 = _mm512_maskz_loadu_epi32(mask, rem.as_ptr().cast());
+
+            k1 = _mm512_mullo_epi32(k1, c1);
+            k1 = _mm512_rol_epi32::<15>(k1);
+            k1 = _mm512_mullo_epi32(k1, c2);
+
+            _mm512_storeu_si512(lanes.as_mut_ptr().cast(), k1);
+        }
+
+        for &k1 in &lanes[..blocks] {
+            h1 ^= k1;
+            h1 = h1.rotate_left(13);
+            h1 = h1.wrapping_mul(5);
+            h1 = h1.wrapping_add(0xe6546b64);
+        }
+    }
+
+    h1
+}
+
+#[cfg(test)]
+mod test {
+    use super::avx512_murmurhash3_32;
+    use crate::generic::murmurhash3_32;
+
+    #[test]
+    fn test_tail_lengths() {
+        if std::arch::is_x86_feature_detected!("avx512f") {
+            unsafe {
+                assert_eq!(avx512_murmurhash3_32("".as_bytes(), 0), 0);
+                assert_eq!(avx512_murmurhash3_32("1".as_bytes(), 0), 2484513939);
+                assert_eq!(avx512_murmurhash3_32("12".as_bytes(), 0), 4191350549);
+                assert_eq!(avx512_murmurhash3_32("123".as_bytes(), 0), 2662625771);
+                assert_eq!(avx512_murmurhash3_32("1234".as_bytes(), 0), 1914461635);
+            }
+        }
+    }
+
+    #[test]
+    fn test_matches_generic() {
+        if std::arch::is_x86_feature_detected!("avx512f") {
+            let data: Vec<u8> = (0..300u32).map(|x| (x * 23 + 9) as u8).collect();
+            for len in 0..data.len() {
+                unsafe {
+                    assert_eq!(
+                        avx512_murmurhash3_32(&data[..len], len as u32),
+                        murmurhash3_32(&data[..len], len as u32)
+                    );
+                }
+            }
+        }
+    }
+}
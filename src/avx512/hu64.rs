@@ -0,0 +1,100 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 10/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::generic64::M;
+use std::arch::x86_64::*;
+
+#[inline]
+#[target_feature(enable = "avx512f,avx512dq")]
+unsafe fn _mm512_mix_k(k: __m512i, m: __m512i) -> __m512i {
+    let k = _mm512_mullo_epi64(k, m);
+    let k = _mm512_xor_si512(k, _mm512_srli_epi64::<47>(k));
+    _mm512_mullo_epi64(k, m)
+}
+
+#[target_feature(enable = "avx512f,avx512dq")]
+pub(crate) unsafe fn avx512_murmurhash2_64_blocks(bytes: &[u8], h: u64) -> u64 {
+    let mut h = h;
+
+    let m = _mm512_set1_epi64(M as i64);
+
+    let mut lanes = [0u64; 8];
+
+    for chunk in bytes.chunks_exact(64) {
+        unsafe {
+            let k = _mm512_loadu_si512(chunk.as_ptr().cast());
+            _mm512_storeu_si512(lanes.as_mut_ptr().cast(), _mm512_mix_k(k, m));
+        }
+
+        for k in lanes {
+            h ^= k;
+            h = h.wrapping_mul(M);
+        }
+    }
+
+    let rem = bytes.chunks_exact(64).remainder();
+    let blocks = rem.len() / 8;
+
+    if blocks != 0 {
+        unsafe {
+            let mask = ((1u32 << blocks) - 1) as __mmask8;
+            let k = _mm512_maskz_loadu_epi64(mask, rem.as_ptr().cast());
+            _mm512_storeu_si512(lanes.as_mut_ptr().cast(), _mm512_mix_k(k, m));
+        }
+
+        for &k in &lanes[..blocks] {
+            h ^= k;
+            h = h.wrapping_mul(M);
+        }
+    }
+
+    h
+}
+
+#[cfg(test)]
+mod test {
+    use super::avx512_murmurhash2_64_blocks;
+    use crate::generic64::murmurhash2_64_blocks;
+
+    #[test]
+    fn test_matches_generic() {
+        if std::arch::is_x86_feature_detected!("avx512f")
+            && std::arch::is_x86_feature_detected!("avx512dq")
+        {
+            let data: Vec<u8> = (0..300u32).map(|x| (x * 23 + 9) as u8).collect();
+            for len in 0..data.len() {
+                unsafe {
+                    assert_eq!(
+                        avx512_murmurhash2_64_blocks(&data[..len], len as u64),
+                        murmurhash2_64_blocks(&data[..len], len as u64)
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,328 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 10/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+
+/// A family of hashing kernels.
+///
+/// Every backend produces bit-identical results; they only differ in speed.
+/// The x86 backends form a ladder: selecting one also allows every lower
+/// x86 tier for algorithms that have no kernel at the selected level, e.g.
+/// MurmurHash64A under [`Backend::Avx2`] runs the generic code.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Backend {
+    /// Portable scalar code, available everywhere.
+    Generic,
+    /// x86 SSE2.
+    Sse2,
+    /// x86 SSE4.1.
+    Sse41,
+    /// x86/x86-64 AVX2, requires the `avx` feature.
+    Avx2,
+    /// x86-64 AVX-512 (F + DQ), requires the `avx512` feature.
+    Avx512,
+    /// AArch64 NEON, requires the `neon` feature.
+    Neon,
+    /// WebAssembly SIMD128, requires the `wasm` feature and the `simd128`
+    /// target feature at compile time.
+    WasmSimd128,
+}
+
+impl Backend {
+    /// All backends, supported or not.
+    pub const ALL: [Backend; 7] = [
+        Backend::Generic,
+        Backend::Sse2,
+        Backend::Sse41,
+        Backend::Avx2,
+        Backend::Avx512,
+        Backend::Neon,
+        Backend::WasmSimd128,
+    ];
+
+    /// Returns `true` if this backend is compiled in and the running CPU
+    /// supports it.
+    pub fn is_supported(self) -> bool {
+        match self {
+            Backend::Generic => true,
+            #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "sse"))]
+            Backend::Sse2 => std::arch::is_x86_feature_detected!("sse2"),
+            // Every x86 tier checks the features of the tiers below it directly,
+            // since it allows their kernels even when their cargo features are off.
+            #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "sse"))]
+            Backend::Sse41 => {
+                std::arch::is_x86_feature_detected!("sse2")
+                    && std::arch::is_x86_feature_detected!("sse4.1")
+            }
+            #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx"))]
+            Backend::Avx2 => {
+                std::arch::is_x86_feature_detected!("sse2")
+                    && std::arch::is_x86_feature_detected!("sse4.1")
+                    && std::arch::is_x86_feature_detected!("avx2")
+            }
+            #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+            Backend::Avx512 => {
+                std::arch::is_x86_feature_detected!("sse2")
+                    && std::arch::is_x86_feature_detected!("sse4.1")
+                    && std::arch::is_x86_feature_detected!("avx2")
+                    && std::arch::is_x86_feature_detected!("avx512f")
+                    && std::arch::is_x86_feature_detected!("avx512dq")
+            }
+            #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+            Backend::Neon => true,
+            #[cfg(all(target_arch = "wasm32", target_feature = "simd128", feature = "wasm"))]
+            Backend::WasmSimd128 => true,
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if kernels written for `tier` may run under this
+    /// backend, i.e. `tier` is this backend or a lower x86 tier of it.
+    #[allow(dead_code)]
+    #[inline(always)]
+    pub(crate) fn allows(self, tier: Backend) -> bool {
+        match (self.x86_rank(), tier.x86_rank()) {
+            (Some(a), Some(b)) => a >= b,
+            _ => self == tier,
+        }
+    }
+
+    #[allow(dead_code)]
+    #[inline(always)]
+    fn x86_rank(self) -> Option<u8> {
+        match self {
+            Backend::Sse2 => Some(1),
+            Backend::Sse41 => Some(2),
+            Backend::Avx2 => Some(3),
+            Backend::Avx512 => Some(4),
+            _ => None,
+        }
+    }
+
+    fn detect() -> Backend {
+        [
+            Backend::Avx512,
+            Backend::Avx2,
+            Backend::Sse41,
+            Backend::Sse2,
+            Backend::Neon,
+            Backend::WasmSimd128,
+        ]
+        .into_iter()
+        .find(|b| b.is_supported())
+        .unwrap_or(Backend::Generic)
+    }
+
+    fn to_u8(self) -> u8 {
+        self as u8 + 1
+    }
+
+    fn from_u8(v: u8) -> Backend {
+        Backend::ALL[(v - 1) as usize]
+    }
+}
+
+/// Error returned when a [`Backend`] is requested that is not compiled in
+/// or not supported by the running CPU.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Unsupported {
+    /// The backend that was requested.
+    pub backend: Backend,
+}
+
+impl fmt::Display for Unsupported {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} backend is not supported on this host",
+            self.backend
+        )
+    }
+}
+
+impl std::error::Error for Unsupported {}
+
+const UNINIT: u8 = 0;
+
+static ACTIVE: AtomicU8 = AtomicU8::new(UNINIT);
+
+static FORCED: AtomicBool = AtomicBool::new(false);
+
+/// The backend whose target features are enabled for the whole crate, if
+/// runtime detection could not pick anything faster.
+///
+/// Dispatchers call its kernels directly, so they inline into the caller,
+/// and only fall back to [`active_backend`] when there is none or a backend
+/// was pinned with [`force_backend`]. The highest tier whose target features
+/// are enabled wins, except that AVX2 stays dynamic while the opt-in
+/// `avx512` feature is compiled in, as the running CPU may support AVX-512.
+#[allow(dead_code)]
+pub(crate) const STATIC_BACKEND: Option<Backend> = if cfg!(all(
+    target_arch = "x86_64",
+    feature = "avx512",
+    target_feature = "avx512f",
+    target_feature = "avx512dq"
+)) {
+    Some(Backend::Avx512)
+} else if cfg!(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "avx",
+    target_feature = "avx2",
+    not(all(target_arch = "x86_64", feature = "avx512"))
+)) {
+    Some(Backend::Avx2)
+} else if cfg!(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "sse",
+    target_feature = "sse4.1"
+)) {
+    Some(Backend::Sse41)
+} else if cfg!(all(
+    target_arch = "aarch64",
+    feature = "neon",
+    target_feature = "neon"
+)) {
+    Some(Backend::Neon)
+} else if cfg!(all(
+    target_arch = "wasm32",
+    target_feature = "simd128",
+    feature = "wasm"
+)) {
+    Some(Backend::WasmSimd128)
+} else {
+    None
+};
+
+/// Returns `true` once a backend was pinned with [`force_backend`].
+#[allow(dead_code)]
+#[inline(always)]
+pub(crate) fn backend_forced() -> bool {
+    FORCED.load(Ordering::Relaxed)
+}
+
+/// Returns the backend the dispatching functions currently use.
+///
+/// Unless overridden with [`force_backend`], this is the fastest backend
+/// supported by the running CPU, detected on first use.
+#[inline]
+pub fn active_backend() -> Backend {
+    match ACTIVE.load(Ordering::Relaxed) {
+        UNINIT => {
+            let detected = Backend::detect();
+            // A concurrent `force_backend` wins over detection.
+            match ACTIVE.compare_exchange(
+                UNINIT,
+                detected.to_u8(),
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => detected,
+                Err(v) => Backend::from_u8(v),
+            }
+        }
+        v => Backend::from_u8(v),
+    }
+}
+
+/// Pins the backend used by all dispatching functions of this crate.
+///
+/// This is process-wide. It is meant for reproducible benchmarks, for
+/// comparing kernels, and for avoiding wide vector units on hosts where
+/// they cause frequency throttling, e.g. `force_backend(Backend::Sse41)`.
+/// Once a backend is forced, kernels enabled at compile time through
+/// `-C target-feature` are no longer called directly either.
+///
+/// # Errors
+/// Returns [`Unsupported`] and leaves the active backend unchanged if
+/// `backend` is not compiled in or not supported by the running CPU.
+pub fn force_backend(backend: Backend) -> Result<(), Unsupported> {
+    if !backend.is_supported() {
+        return Err(Unsupported { backend });
+    }
+    FORCED.store(true, Ordering::Relaxed);
+    ACTIVE.store(backend.to_u8(), Ordering::Relaxed);
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for backend in Backend::ALL {
+            assert_eq!(Backend::from_u8(backend.to_u8()), backend);
+        }
+    }
+
+    #[test]
+    fn test_detected_is_supported() {
+        assert!(Backend::detect().is_supported());
+        assert!(active_backend().is_supported());
+    }
+
+    #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+    #[test]
+    fn test_avx512_without_lower_features() {
+        let host = std::arch::is_x86_feature_detected!("avx2")
+            && std::arch::is_x86_feature_detected!("avx512f")
+            && std::arch::is_x86_feature_detected!("avx512dq");
+        assert_eq!(Backend::Avx512.is_supported(), host);
+    }
+
+    #[test]
+    fn test_static_backend_is_supported() {
+        if let Some(backend) = STATIC_BACKEND {
+            assert!(backend.is_supported());
+            assert!(Backend::detect().allows(backend));
+        }
+    }
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "sse",
+        target_feature = "sse4.1",
+        not(target_feature = "avx2")
+    ))]
+    #[test]
+    fn test_sse41_is_static() {
+        assert_eq!(STATIC_BACKEND, Some(Backend::Sse41));
+    }
+
+    #[test]
+    fn test_allows() {
+        assert!(Backend::Avx512.allows(Backend::Sse2));
+        assert!(Backend::Sse41.allows(Backend::Sse41));
+        assert!(!Backend::Sse41.allows(Backend::Avx2));
+        assert!(!Backend::Generic.allows(Backend::Sse2));
+        assert!(!Backend::Neon.allows(Backend::Sse2));
+    }
+}
@@ -0,0 +1,389 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 10/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+use crate::backend::{Backend, Unsupported, active_backend};
+use crate::generic32::{read_le32, read_tail};
+use crate::hasher::murmurhash3_32_kernel;
+
+/// Hashes many independent keys with **MurmurHash3 (x86_32)**, one key per
+/// SIMD lane.
+///
+/// The single-key kernels can only vectorize the block pre-mix, the `h1`
+/// chain itself is serial. Here 4 (SSE, NEON), 8 (AVX2) or 16 (AVX-512)
+/// keys are hashed side by side instead, which is what makes short keys
+/// fast. Keys of different lengths are fine, lanes that run out of blocks
+/// are masked off.
+///
+/// Every `out[i]` is exactly `murmurhash3_32_with_seed(keys[i], seed)`.
+///
+/// # Panics
+/// Panics if `keys` and `out` have different lengths.
+pub fn murmurhash3_32_batch(keys: &[&[u8]], seed: u32, out: &mut [u32]) {
+    assert_eq!(
+        keys.len(),
+        out.len(),
+        "keys and out must have the same length"
+    );
+    unsafe { murmurhash3_32_batch_kernel(keys, seed, out, active_backend()) }
+}
+
+/// Hashes many independent keys like [`murmurhash3_32_batch`] with the
+/// kernels of an explicitly chosen [`Backend`], regardless of
+/// [`active_backend`].
+///
+/// This is meant for testing and benchmarking individual kernels.
+///
+/// # Errors
+/// Returns [`Unsupported`] if `backend` is not compiled in or not supported
+/// by the running CPU.
+///
+/// # Panics
+/// Panics if `keys` and `out` have different lengths.
+pub fn murmurhash3_32_batch_with_backend(
+    keys: &[&[u8]],
+    seed: u32,
+    out: &mut [u32],
+    backend: Backend,
+) -> Result<(), Unsupported> {
+    assert_eq!(
+        keys.len(),
+        out.len(),
+        "keys and out must have the same length"
+    );
+    if !backend.is_supported() {
+        return Err(Unsupported { backend });
+    }
+    unsafe { murmurhash3_32_batch_kernel(keys, seed, out, backend) };
+    Ok(())
+}
+
+/// Hashes `keys` into `out` with the batch kernel of `backend`.
+///
+/// # Safety
+/// `backend` must be supported by the running CPU.
+unsafe fn murmurhash3_32_batch_kernel(
+    keys: &[&[u8]],
+    seed: u32,
+    out: &mut [u32],
+    backend: Backend,
+) {
+    debug_assert!(backend.is_supported());
+    #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+    {
+        use crate::neon::neon_murmurhash3_32_batch;
+        if backend == Backend::Neon {
+            return neon_murmurhash3_32_batch(keys, seed, out);
+        }
+    }
+    #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+    {
+        use crate::avx512::avx512_murmurhash3_32_batch;
+        if backend.allows(Backend::Avx512) {
+            return unsafe { avx512_murmurhash3_32_batch(keys, seed, out) };
+        }
+    }
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx"))]
+    {
+        use crate::avx::avx_murmurhash3_32_batch;
+        if backend.allows(Backend::Avx2) {
+            return unsafe { avx_murmurhash3_32_batch(keys, seed, out) };
+        }
+    }
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "sse"))]
+    {
+        use crate::sse::{sse2_murmurhash3_32_batch, sse41_murmurhash3_32_batch};
+        if backend.allows(Backend::Sse41) {
+            return unsafe { sse41_murmurhash3_32_batch(keys, seed, out) };
+        }
+        if backend.allows(Backend::Sse2) {
+            return unsafe { sse2_murmurhash3_32_batch(keys, seed, out) };
+        }
+    }
+    for (key, dst) in keys.iter().zip(out.iter_mut()) {
+        *dst = unsafe { murmurhash3_32_kernel(key, seed, backend) };
+    }
+}
+
+/// Hashes `out.len()` equal-length keys packed back to back in `keys`, one
+/// key per SIMD lane.
+///
+/// Key `i` is `keys[i * key_len..(i + 1) * key_len]` and
+/// `out[i]` is exactly `murmurhash3_32_with_seed` of it. Since every lane
+/// has the same number of blocks, no lane masking is needed at all.
+///
+/// # Panics
+/// Panics if `keys.len() != key_len * out.len()`.
+pub fn murmurhash3_32_batch_fixed(keys: &[u8], key_len: usize, seed: u32, out: &mut [u32]) {
+    assert_eq!(
+        Some(keys.len()),
+        key_len.checked_mul(out.len()),
+        "keys must hold exactly out.len() keys of key_len bytes"
+    );
+    unsafe { murmurhash3_32_batch_fixed_kernel(keys, key_len, seed, out, active_backend()) }
+}
+
+/// Hashes equal-length keys like [`murmurhash3_32_batch_fixed`] with the
+/// kernels of an explicitly chosen [`Backend`], regardless of
+/// [`active_backend`].
+///
+/// This is meant for testing and benchmarking individual kernels.
+///
+/// # Errors
+/// Returns [`Unsupported`] if `backend` is not compiled in or not supported
+/// by the running CPU.
+///
+/// # Panics
+/// Panics if `keys.len() != key_len * out.len()`.
+pub fn murmurhash3_32_batch_fixed_with_backend(
+    keys: &[u8],
+    key_len: usize,
+    seed: u32,
+    out: &mut [u32],
+    backend: Backend,
+) -> Result<(), Unsupported> {
+    assert_eq!(
+        Some(keys.len()),
+        key_len.checked_mul(out.len()),
+        "keys must hold exactly out.len() keys of key_len bytes"
+    );
+    if !backend.is_supported() {
+        return Err(Unsupported { backend });
+    }
+    unsafe { murmurhash3_32_batch_fixed_kernel(keys, key_len, seed, out, backend) };
+    Ok(())
+}
+
+/// Hashes the packed `keys` into `out` with the batch kernel of `backend`.
+///
+/// # Safety
+/// `backend` must be supported by the running CPU.
+unsafe fn murmurhash3_32_batch_fixed_kernel(
+    keys: &[u8],
+    key_len: usize,
+    seed: u32,
+    out: &mut [u32],
+    backend: Backend,
+) {
+    debug_assert!(backend.is_supported());
+    if key_len == 0 {
+        out.fill(crate::generic::murmurhash3_32(&[], seed));
+        return;
+    }
+
+    #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+    {
+        use crate::neon::neon_murmurhash3_32_batch_fixed;
+        if backend == Backend::Neon {
+            return neon_murmurhash3_32_batch_fixed(keys, key_len, seed, out);
+        }
+    }
+    #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+    {
+        use crate::avx512::avx512_murmurhash3_32_batch_fixed;
+        if backend.allows(Backend::Avx512) {
+            return unsafe { avx512_murmurhash3_32_batch_fixed(keys, key_len, seed, out) };
+        }
+    }
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx"))]
+    {
+        use crate::avx::avx_murmurhash3_32_batch_fixed;
+        if backend.allows(Backend::Avx2) {
+            return unsafe { avx_murmurhash3_32_batch_fixed(keys, key_len, seed, out) };
+        }
+    }
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "sse"))]
+    {
+        use crate::sse::{sse2_murmurhash3_32_batch_fixed, sse41_murmurhash3_32_batch_fixed};
+        if backend.allows(Backend::Sse41) {
+            return unsafe { sse41_murmurhash3_32_batch_fixed(keys, key_len, seed, out) };
+        }
+        if backend.allows(Backend::Sse2) {
+            return unsafe { sse2_murmurhash3_32_batch_fixed(keys, key_len, seed, out) };
+        }
+    }
+    for (key, dst) in keys.chunks_exact(key_len).zip(out.iter_mut()) {
+        *dst = unsafe { murmurhash3_32_kernel(key, seed, backend) };
+    }
+}
+
+/// Feeds `keys` to `kernel` in groups of `N` lanes and scatters the lane
+/// results into `out`. The last group is padded with empty keys whose
+/// results are discarded.
+#[allow(dead_code)]
+#[inline(always)]
+pub(crate) fn hash_lane_groups<'a, const N: usize>(
+    mut keys: impl Iterator<Item = &'a [u8]>,
+    out: &mut [u32],
+    mut kernel: impl FnMut(&[&'a [u8]; N]) -> [u32; N],
+) {
+    for dst in out.chunks_mut(N) {
+        let mut group: [&[u8]; N] = [&[]; N];
+        for lane in group.iter_mut().take(dst.len()) {
+            *lane = keys.next().unwrap_or_default();
+        }
+        let hashes = kernel(&group);
+        dst.copy_from_slice(&hashes[..dst.len()]);
+    }
+}
+
+/// Number of complete 4-byte blocks of every lane.
+#[allow(dead_code)]
+#[inline(always)]
+pub(crate) fn lane_blocks<const N: usize>(keys: &[&[u8]; N]) -> [usize; N] {
+    keys.map(|key| key.len() / 4)
+}
+
+/// Block `i` of every lane, or `0` for lanes that have fewer blocks.
+#[allow(dead_code)]
+#[inline(always)]
+pub(crate) fn lane_words<const N: usize>(keys: &[&[u8]; N], i: usize) -> [u32; N] {
+    keys.map(|key| key.get(i * 4..i * 4 + 4).map_or(0, read_le32))
+}
+
+/// Block `i` of every lane without bounds checks.
+///
+/// # Safety
+/// Every lane must have more than `i` complete blocks.
+#[allow(dead_code)]
+#[inline(always)]
+pub(crate) unsafe fn lane_words_unchecked<const N: usize>(keys: &[&[u8]; N], i: usize) -> [u32; N] {
+    keys.map(|key| unsafe { u32::from_le(key.as_ptr().add(i * 4).cast::<u32>().read_unaligned()) })
+}
+
+/// All-ones for lanes that still have block `i`, zero otherwise.
+#[allow(dead_code)]
+#[inline(always)]
+pub(crate) fn lane_active<const N: usize>(blocks: &[usize; N], i: usize) -> [u32; N] {
+    blocks.map(|b| if i < b { u32::MAX } else { 0 })
+}
+
+/// The unmixed tail word of every lane, `0` when the key length is a
+/// multiple of 4.
+#[allow(dead_code)]
+#[inline(always)]
+pub(crate) fn lane_tails<const N: usize>(keys: &[&[u8]; N]) -> [u32; N] {
+    let mut tails = [0u32; N];
+    for (tail, key) in tails.iter_mut().zip(keys.iter()) {
+        *tail = if key.len() >= 4 {
+            // The last `len % 4` bytes are the top bytes of the final word.
+            let word = read_le32(&key[key.len() - 4..]) as u64;
+            (word >> (32 - (key.len() & 3) * 8)) as u32
+        } else {
+            read_tail(key)
+        };
+    }
+    tails
+}
+
+#[allow(dead_code)]
+#[inline(always)]
+pub(crate) fn lane_lens<const N: usize>(keys: &[&[u8]; N]) -> [u32; N] {
+    keys.map(|key| key.len() as u32)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::murmurhash3_32_with_seed;
+
+    fn keys() -> Vec<Vec<u8>> {
+        (0..517u32)
+            .map(|i| (0..(i * 7) % 71).map(|x| (x * 31 + i) as u8).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_batch_matches_single() {
+        let owned = keys();
+        let keys: Vec<&[u8]> = owned.iter().map(|k| k.as_slice()).collect();
+        for n in [0, 1, 3, 4, 5, 8, 15, 16, 17, keys.len()] {
+            let mut out = vec![0u32; n];
+            murmurhash3_32_batch(&keys[..n], 0x9747b28c, &mut out);
+            for (key, &hash) in keys[..n].iter().zip(out.iter()) {
+                assert_eq!(hash, murmurhash3_32_with_seed(key, 0x9747b28c));
+            }
+        }
+    }
+
+    #[test]
+    fn test_batch_fixed_matches_single() {
+        let data: Vec<u8> = (0..4096u32).map(|x| (x * 13 + 5) as u8).collect();
+        for key_len in [0, 1, 3, 4, 8, 13, 16, 33, 64] {
+            for n in [0, 1, 7, 16, 17, 33] {
+                let keys = &data[..key_len * n];
+                let mut out = vec![0u32; n];
+                murmurhash3_32_batch_fixed(keys, key_len, 42, &mut out);
+                for (i, &hash) in out.iter().enumerate() {
+                    let key = &keys[i * key_len..(i + 1) * key_len];
+                    assert_eq!(hash, murmurhash3_32_with_seed(key, 42));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_every_backend_matches_single() {
+        let owned = keys();
+        let keys: Vec<&[u8]> = owned.iter().map(|k| k.as_slice()).collect();
+        let data: Vec<u8> = (0..1024u32).map(|x| (x * 13 + 5) as u8).collect();
+        for backend in Backend::ALL {
+            let mut out = vec![0u32; keys.len()];
+            if !backend.is_supported() {
+                assert_eq!(
+                    murmurhash3_32_batch_with_backend(&keys, 7, &mut out, backend),
+                    Err(Unsupported { backend })
+                );
+                continue;
+            }
+            for n in [0, 1, 5, 16, 17, keys.len()] {
+                murmurhash3_32_batch_with_backend(&keys[..n], 7, &mut out[..n], backend).unwrap();
+                for (key, &hash) in keys[..n].iter().zip(out.iter()) {
+                    assert_eq!(hash, murmurhash3_32_with_seed(key, 7), "{backend:?}");
+                }
+            }
+            for key_len in [0, 1, 3, 4, 13, 16, 33] {
+                let n = 31;
+                let packed = &data[..key_len * n];
+                murmurhash3_32_batch_fixed_with_backend(packed, key_len, 7, &mut out[..n], backend)
+                    .unwrap();
+                for (i, &hash) in out[..n].iter().enumerate() {
+                    let key = &packed[i * key_len..(i + 1) * key_len];
+                    assert_eq!(hash, murmurhash3_32_with_seed(key, 7), "{backend:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_batch_len_mismatch() {
+        let mut out = [0u32; 2];
+        murmurhash3_32_batch(&[b"a"], 0, &mut out);
+    }
+}
@@ -0,0 +1,74 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 10/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+/// Number of bytes [`InlineBuffer`] keeps inline before spilling to the heap.
+const INLINE_LEN: usize = 64;
+
+/// Collects written bytes for hashes that can only run over the complete
+/// input, keeping up to 64 bytes inline so typical keys never allocate.
+#[derive(Clone, Debug)]
+pub(crate) struct InlineBuffer {
+    inline: [u8; INLINE_LEN],
+    len: usize,
+    spill: Vec<u8>,
+}
+
+impl InlineBuffer {
+    pub(crate) const fn new() -> Self {
+        Self {
+            inline: [0; INLINE_LEN],
+            len: 0,
+            spill: Vec::new(),
+        }
+    }
+
+    /// Appends `bytes`, moving everything to the heap once it no longer fits inline.
+    pub(crate) fn extend(&mut self, bytes: &[u8]) {
+        let len = self.len + bytes.len();
+        if self.spill.is_empty() && len <= INLINE_LEN {
+            self.inline[self.len..len].copy_from_slice(bytes);
+        } else {
+            if self.spill.is_empty() {
+                self.spill.reserve(len);
+                self.spill.extend_from_slice(&self.inline[..self.len]);
+            }
+            self.spill.extend_from_slice(bytes);
+        }
+        self.len = len;
+    }
+
+    /// Returns all bytes appended so far.
+    pub(crate) fn as_slice(&self) -> &[u8] {
+        if self.spill.is_empty() {
+            &self.inline[..self.len]
+        } else {
+            &self.spill
+        }
+    }
+}
@@ -0,0 +1,215 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 10/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::generic64::Murmur64AHasher;
+use crate::generic128::Murmur3x64_128;
+use crate::hasher::Murmur3Hasher;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::hash::BuildHasher;
+
+/// Derives a fresh seed from the standard library's randomly keyed SipHash.
+///
+/// Every `RandomState::new` call yields different keys, so this gives a new
+/// seed per call without any extra dependency.
+fn random_seed() -> u64 {
+    std::collections::hash_map::RandomState::new().hash_one(0x6d6d_3368u32)
+}
+
+macro_rules! build_hashers {
+    (
+        $hasher:ident, $seed:ty, $algorithm:literal,
+        $build:ident, $random:ident, $map:ident, $set:ident
+    ) => {
+        #[doc = concat!("A [`BuildHasher`] creating [`", stringify!($hasher), "`]s with a fixed seed.")]
+        ///
+        /// Maps built with it hash deterministically across runs and
+        /// processes. It can be constructed in `const` context and is the
+        /// [`Default`] state of the map aliases.
+        #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+        pub struct $build {
+            seed: $seed,
+        }
+
+        impl $build {
+            #[doc = concat!("Creates a [`", stringify!($build), "`] with a seed of `0`.")]
+            pub const fn new() -> Self {
+                Self::with_seed(0)
+            }
+
+            #[doc = concat!("Creates a [`", stringify!($build), "`] with the specified seed.")]
+            pub const fn with_seed(seed: $seed) -> Self {
+                Self { seed }
+            }
+
+            /// Returns the seed passed to every hasher.
+            pub const fn seed(&self) -> $seed {
+                self.seed
+            }
+        }
+
+        impl BuildHasher for $build {
+            type Hasher = $hasher;
+
+            #[inline]
+            fn build_hasher(&self) -> $hasher {
+                $hasher::new_with_seed(self.seed)
+            }
+        }
+
+        #[doc = concat!("A [`BuildHasher`] creating [`", stringify!($hasher), "`]s with a random seed.")]
+        ///
+        /// Each call to `new` (and so each map created with `default`) draws
+        /// a new seed from [`std::collections::hash_map::RandomState`]; clones
+        /// share the seed. This only hides the seed, it does not make
+        #[doc = concat!($algorithm, " resistant to deliberately crafted collisions.")]
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        pub struct $random {
+            seed: $seed,
+        }
+
+        impl $random {
+            #[doc = concat!("Creates a [`", stringify!($random), "`] with a new random seed.")]
+            pub fn new() -> Self {
+                Self {
+                    seed: random_seed() as $seed,
+                }
+            }
+        }
+
+        impl Default for $random {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl fmt::Debug for $random {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($random)).finish_non_exhaustive()
+            }
+        }
+
+        impl BuildHasher for $random {
+            type Hasher = $hasher;
+
+            #[inline]
+            fn build_hasher(&self) -> $hasher {
+                $hasher::new_with_seed(self.seed)
+            }
+        }
+
+        #[doc = concat!("A [`HashMap`] hashing keys with ", $algorithm, ".")]
+        pub type $map<K, V, S = $build> = HashMap<K, V, S>;
+
+        #[doc = concat!("A [`HashSet`] hashing values with ", $algorithm, ".")]
+        pub type $set<T, S = $build> = HashSet<T, S>;
+    };
+}
+
+build_hashers!(
+    Murmur3Hasher,
+    u32,
+    "MurmurHash3 (x86_32)",
+    Murmur3BuildHasher,
+    Murmur3RandomState,
+    Murmur3HashMap,
+    Murmur3HashSet
+);
+
+build_hashers!(
+    Murmur64AHasher,
+    u64,
+    "MurmurHash64A",
+    Murmur64ABuildHasher,
+    Murmur64ARandomState,
+    Murmur64AHashMap,
+    Murmur64AHashSet
+);
+
+build_hashers!(
+    Murmur3x64_128,
+    u32,
+    "MurmurHash3 (x64_128)",
+    Murmur3x64_128BuildHasher,
+    Murmur3x64_128RandomState,
+    Murmur3x64_128HashMap,
+    Murmur3x64_128HashSet
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::hash::Hasher;
+
+    const FIXED: Murmur3BuildHasher = Murmur3BuildHasher::with_seed(42);
+
+    #[test]
+    fn fixed_seed_matches_hasher() {
+        let mut hasher = Murmur3Hasher::new_with_seed(42);
+        hasher.write_u32(5);
+        assert_eq!(FIXED.hash_one(5u32), hasher.finish());
+        assert_eq!(FIXED.seed(), 42);
+        assert_eq!(Murmur3BuildHasher::default(), Murmur3BuildHasher::new());
+        assert_eq!(
+            Murmur64ABuildHasher::with_seed(7).hash_one(5u64),
+            crate::murmurhash2_64_with_seed(&5u64.to_le_bytes(), 7)
+        );
+        assert_eq!(
+            Murmur3x64_128BuildHasher::with_seed(7).hash_one(5u64),
+            crate::murmurhash3_128_with_seed(&5u64.to_le_bytes(), 7) as u64
+        );
+    }
+
+    #[test]
+    fn random_state_keeps_its_seed() {
+        let a = Murmur3RandomState::new();
+        assert_eq!(
+            a.hash_one("key"),
+            Murmur3BuildHasher::with_seed(a.seed).hash_one("key")
+        );
+        let b = Clone::clone(&a);
+        assert_eq!(b.seed, a.seed);
+        assert_eq!(b.hash_one("key"), a.hash_one("key"));
+        assert_eq!(format!("{a:?}"), "Murmur3RandomState { .. }");
+    }
+
+    #[test]
+    fn maps_and_sets() {
+        let mut map: Murmur3HashMap<&str, u32> = Murmur3HashMap::default();
+        map.insert("one", 1);
+        map.insert("two", 2);
+        assert_eq!(map.get("two"), Some(&2));
+
+        let mut random: Murmur64AHashMap<u64, u64, Murmur64ARandomState> = Default::default();
+        random.extend((0..1000u64).map(|i| (i, i * 2)));
+        assert!((0..1000u64).all(|i| random[&i] == i * 2));
+
+        let set: Murmur3x64_128HashSet<u32> = (0..100).collect();
+        assert!(set.contains(&99) && !set.contains(&100));
+    }
+}
@@ -0,0 +1,340 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 10/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+use crate::backend::{Backend, active_backend};
+use crate::generic::{murmurhash3_32_round, scramble};
+use crate::generic64::{M as M_64, murmurhash2_64_round};
+use crate::generic128::{C1 as C1_128, C2 as C2_128, fmix64};
+
+#[inline(always)]
+fn murmurhash3_32_u32(value: u32, seed: u32) -> u32 {
+    let h1 = murmurhash3_32_round(seed, value);
+    scramble(h1 ^ 4)
+}
+
+#[inline(always)]
+fn murmurhash3_32_u64(value: u64, seed: u32) -> u32 {
+    let h1 = murmurhash3_32_round(seed, value as u32);
+    let h1 = murmurhash3_32_round(h1, (value >> 32) as u32);
+    scramble(h1 ^ 8)
+}
+
+/// Inputs shorter than 16 bytes never reach a block, only the `k1` tail.
+#[inline(always)]
+fn murmurhash3_128_tail(value: u64, seed: u32, len: u64) -> u128 {
+    let k1 = value
+        .wrapping_mul(C1_128)
+        .rotate_left(31)
+        .wrapping_mul(C2_128);
+    let mut h1 = (seed as u64) ^ k1 ^ len;
+    let mut h2 = (seed as u64) ^ len;
+    h1 = h1.wrapping_add(h2);
+    h2 = h2.wrapping_add(h1);
+    h1 = fmix64(h1);
+    h2 = fmix64(h2);
+    h1 = h1.wrapping_add(h2);
+    h2 = h2.wrapping_add(h1);
+    ((h2 as u128) << 64) | (h1 as u128)
+}
+
+#[inline(always)]
+fn murmurhash2_64_final(mut h: u64) -> u64 {
+    h ^= h >> 47;
+    h = h.wrapping_mul(M_64);
+    h ^= h >> 47;
+    h
+}
+
+#[inline(always)]
+fn murmurhash2_64_u32(value: u32, seed: u64) -> u64 {
+    let h = seed ^ 4u64.wrapping_mul(M_64);
+    murmurhash2_64_final((h ^ value as u64).wrapping_mul(M_64))
+}
+
+#[inline(always)]
+fn murmurhash2_64_u64(value: u64, seed: u64) -> u64 {
+    let h = seed ^ 8u64.wrapping_mul(M_64);
+    murmurhash2_64_final(murmurhash2_64_round(h, value))
+}
+
+#[inline(always)]
+fn map_column<T: Copy, O>(values: &[T], out: &mut [O], f: impl Fn(T) -> O) {
+    for (&value, dst) in values.iter().zip(out.iter_mut()) {
+        *dst = f(value);
+    }
+}
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx"))]
+#[target_feature(enable = "avx2")]
+unsafe fn avx_map_column<T: Copy, O>(values: &[T], out: &mut [O], f: impl Fn(T) -> O) {
+    map_column(values, out, f)
+}
+
+#[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+#[target_feature(enable = "avx512f,avx512dq")]
+unsafe fn avx512_map_column<T: Copy, O>(values: &[T], out: &mut [O], f: impl Fn(T) -> O) {
+    map_column(values, out, f)
+}
+
+/// Runs the row function over the column, compiled for the widest vector
+/// extension the active backend allows.
+///
+/// With the input length known up front the block, tail and length handling
+/// of every row collapse into straight-line code, which the compiler
+/// vectorizes across rows.
+fn hash_column<T: Copy, O>(values: &[T], out: &mut [O], f: impl Fn(T) -> O) {
+    assert_eq!(
+        values.len(),
+        out.len(),
+        "values and out must have the same length"
+    );
+    unsafe { hash_column_kernel(values, out, active_backend(), f) }
+}
+
+/// Runs the row function over the column, compiled for the widest vector
+/// extension `backend` allows.
+///
+/// # Safety
+/// `backend` must be supported by the running CPU.
+unsafe fn hash_column_kernel<T: Copy, O>(
+    values: &[T],
+    out: &mut [O],
+    backend: Backend,
+    f: impl Fn(T) -> O,
+) {
+    debug_assert!(backend.is_supported());
+    #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+    {
+        if backend.allows(Backend::Avx512) {
+            return unsafe { avx512_map_column(values, out, f) };
+        }
+    }
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx"))]
+    {
+        if backend.allows(Backend::Avx2) {
+            return unsafe { avx_map_column(values, out, f) };
+        }
+    }
+
+    map_column(values, out, f)
+}
+
+/// Hashes every value of a `u32` column with **MurmurHash3 (x86_32)**.
+///
+/// `out[i]` equals `murmurhash3_32_with_seed(&values[i].to_le_bytes(), seed)`.
+///
+/// # Panics
+/// Panics if `values` and `out` have different lengths.
+pub fn hash_u32_column(values: &[u32], seed: u32, out: &mut [u32]) {
+    hash_column(values, out, |v| murmurhash3_32_u32(v, seed))
+}
+
+/// Hashes every value of a `u64` column with **MurmurHash3 (x86_32)**.
+///
+/// `out[i]` equals `murmurhash3_32_with_seed(&values[i].to_le_bytes(), seed)`.
+///
+/// # Panics
+/// Panics if `values` and `out` have different lengths.
+pub fn hash_u64_column(values: &[u64], seed: u32, out: &mut [u32]) {
+    hash_column(values, out, |v| murmurhash3_32_u64(v, seed))
+}
+
+/// Hashes every value of an `i64` column with **MurmurHash3 (x86_32)**.
+///
+/// `out[i]` equals `murmurhash3_32_with_seed(&values[i].to_le_bytes(), seed)`.
+///
+/// # Panics
+/// Panics if `values` and `out` have different lengths.
+pub fn hash_i64_column(values: &[i64], seed: u32, out: &mut [u32]) {
+    hash_column(values, out, |v| murmurhash3_32_u64(v as u64, seed))
+}
+
+/// Hashes every value of a `u32` column with **MurmurHash3 (x64_128)**.
+///
+/// `out[i]` equals `murmurhash3_128_with_seed(&values[i].to_le_bytes(), seed)`.
+///
+/// # Panics
+/// Panics if `values` and `out` have different lengths.
+pub fn hash_u32_column_128(values: &[u32], seed: u32, out: &mut [u128]) {
+    hash_column(values, out, |v| murmurhash3_128_tail(v as u64, seed, 4))
+}
+
+/// Hashes every value of a `u64` column with **MurmurHash3 (x64_128)**.
+///
+/// `out[i]` equals `murmurhash3_128_with_seed(&values[i].to_le_bytes(), seed)`.
+///
+/// # Panics
+/// Panics if `values` and `out` have different lengths.
+pub fn hash_u64_column_128(values: &[u64], seed: u32, out: &mut [u128]) {
+    hash_column(values, out, |v| murmurhash3_128_tail(v, seed, 8))
+}
+
+/// Hashes every value of an `i64` column with **MurmurHash3 (x64_128)**.
+///
+/// `out[i]` equals `murmurhash3_128_with_seed(&values[i].to_le_bytes(), seed)`.
+///
+/// # Panics
+/// Panics if `values` and `out` have different lengths.
+pub fn hash_i64_column_128(values: &[i64], seed: u32, out: &mut [u128]) {
+    hash_column(values, out, |v| murmurhash3_128_tail(v as u64, seed, 8))
+}
+
+/// Hashes every value of a `u32` column with **MurmurHash64A**.
+///
+/// `out[i]` equals `murmurhash2_64_with_seed(&values[i].to_le_bytes(), seed)`.
+///
+/// # Panics
+/// Panics if `values` and `out` have different lengths.
+pub fn hash_u32_column_64a(values: &[u32], seed: u64, out: &mut [u64]) {
+    hash_column(values, out, |v| murmurhash2_64_u32(v, seed))
+}
+
+/// Hashes every value of a `u64` column with **MurmurHash64A**.
+///
+/// `out[i]` equals `murmurhash2_64_with_seed(&values[i].to_le_bytes(), seed)`.
+///
+/// # Panics
+/// Panics if `values` and `out` have different lengths.
+pub fn hash_u64_column_64a(values: &[u64], seed: u64, out: &mut [u64]) {
+    hash_column(values, out, |v| murmurhash2_64_u64(v, seed))
+}
+
+/// Hashes every value of an `i64` column with **MurmurHash64A**.
+///
+/// `out[i]` equals `murmurhash2_64_with_seed(&values[i].to_le_bytes(), seed)`.
+///
+/// # Panics
+/// Panics if `values` and `out` have different lengths.
+pub fn hash_i64_column_64a(values: &[i64], seed: u64, out: &mut [u64]) {
+    hash_column(values, out, |v| murmurhash2_64_u64(v as u64, seed))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{murmurhash2_64_with_seed, murmurhash3_32_with_seed, murmurhash3_128_with_seed};
+
+    fn values() -> Vec<u64> {
+        let mut x = 0x9e37_79b9_7f4a_7c15u64;
+        let mut values = vec![0, 1, u32::MAX as u64, u64::MAX, 1 << 63];
+        for _ in 0..200 {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            values.push(x);
+        }
+        values
+    }
+
+    #[test]
+    fn test_every_backend_matches_single() {
+        let values = values();
+        for backend in Backend::ALL.into_iter().filter(|b| b.is_supported()) {
+            let mut out32 = vec![0u32; values.len()];
+            let mut out128 = vec![0u128; values.len()];
+            let mut out64 = vec![0u64; values.len()];
+            unsafe {
+                hash_column_kernel(&values, &mut out32, backend, |v| murmurhash3_32_u64(v, 31));
+                hash_column_kernel(&values, &mut out128, backend, |v| {
+                    murmurhash3_128_tail(v, 31, 8)
+                });
+                hash_column_kernel(&values, &mut out64, backend, |v| murmurhash2_64_u64(v, 31));
+            }
+            for (i, v) in values.iter().enumerate() {
+                let bytes = v.to_le_bytes();
+                assert_eq!(
+                    out32[i],
+                    murmurhash3_32_with_seed(&bytes, 31),
+                    "{backend:?}"
+                );
+                assert_eq!(
+                    out128[i],
+                    murmurhash3_128_with_seed(&bytes, 31),
+                    "{backend:?}"
+                );
+                assert_eq!(
+                    out64[i],
+                    murmurhash2_64_with_seed(&bytes, 31),
+                    "{backend:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_u32_columns() {
+        let values: Vec<u32> = values().iter().map(|&v| v as u32).collect();
+        let mut out32 = vec![0u32; values.len()];
+        let mut out128 = vec![0u128; values.len()];
+        let mut out64 = vec![0u64; values.len()];
+        hash_u32_column(&values, 31, &mut out32);
+        hash_u32_column_128(&values, 31, &mut out128);
+        hash_u32_column_64a(&values, 31, &mut out64);
+        for (i, v) in values.iter().enumerate() {
+            let bytes = v.to_le_bytes();
+            assert_eq!(out32[i], murmurhash3_32_with_seed(&bytes, 31));
+            assert_eq!(out128[i], murmurhash3_128_with_seed(&bytes, 31));
+            assert_eq!(out64[i], murmurhash2_64_with_seed(&bytes, 31));
+        }
+    }
+
+    #[test]
+    fn test_u64_columns() {
+        let values = values();
+        let mut out32 = vec![0u32; values.len()];
+        let mut out128 = vec![0u128; values.len()];
+        let mut out64 = vec![0u64; values.len()];
+        hash_u64_column(&values, 31, &mut out32);
+        hash_u64_column_128(&values, 31, &mut out128);
+        hash_u64_column_64a(&values, 31, &mut out64);
+        for (i, v) in values.iter().enumerate() {
+            let bytes = v.to_le_bytes();
+            assert_eq!(out32[i], murmurhash3_32_with_seed(&bytes, 31));
+            assert_eq!(out128[i], murmurhash3_128_with_seed(&bytes, 31));
+            assert_eq!(out64[i], murmurhash2_64_with_seed(&bytes, 31));
+        }
+    }
+
+    #[test]
+    fn test_i64_columns() {
+        let values: Vec<i64> = values().iter().map(|&v| v as i64).collect();
+        let mut out32 = vec![0u32; values.len()];
+        let mut out128 = vec![0u128; values.len()];
+        let mut out64 = vec![0u64; values.len()];
+        hash_i64_column(&values, 31, &mut out32);
+        hash_i64_column_128(&values, 31, &mut out128);
+        hash_i64_column_64a(&values, 31, &mut out64);
+        for (i, v) in values.iter().enumerate() {
+            let bytes = v.to_le_bytes();
+            assert_eq!(out32[i], murmurhash3_32_with_seed(&bytes, 31));
+            assert_eq!(out128[i], murmurhash3_128_with_seed(&bytes, 31));
+            assert_eq!(out64[i], murmurhash2_64_with_seed(&bytes, 31));
+        }
+    }
+}
@@ -28,7 +28,7 @@
  */
 
 #[inline]
-pub(crate) fn scramble(mut h: u32) -> u32 {
+pub(crate) const fn scramble(mut h: u32) -> u32 {
     h ^= h >> 16;
     h = h.wrapping_mul(0x85ebca6b);
     h ^= h >> 13;
@@ -37,23 +37,45 @@ pub(crate) fn scramble(mut h: u32) -> u32 {
     h
 }
 
-#[allow(dead_code)]
+#[inline]
 pub(crate) fn murmurhash3_32(bytes: &[u8], seed: u32) -> u32 {
+    let h1 = murmurhash3_32_blocks(bytes, seed);
+    murmurhash3_32_finalize(bytes.chunks_exact(4).remainder(), h1, bytes.len())
+}
+
+/// Mixes a single 4-byte block `k1` into `h1`.
+#[inline(always)]
+pub(crate) const fn murmurhash3_32_round(h1: u32, k1: u32) -> u32 {
     const C1: u32 = 0xcc9e2d51u32;
     const C2: u32 = 0x1b873593u32;
 
-    let read_size = 4;
-    let len = bytes.len() as u32;
-    let block_count = len / read_size;
+    let mut k1 = k1;
+    k1 = k1.wrapping_mul(C1);
+    k1 = k1.rotate_left(15);
+    k1 = k1.wrapping_mul(C2);
 
-    let mut h1 = seed;
+    let mut h1 = h1 ^ k1;
+    h1 = h1.rotate_left(13);
+    h1 = h1.wrapping_mul(5);
+    h1.wrapping_add(0xe6546b64)
+}
+
+/// Mixes every complete 4-byte block of `bytes` into `h1`.
+///
+/// Trailing bytes that do not form a full block are ignored and must be
+/// passed later to [`murmurhash3_32_finalize`].
+pub(crate) fn murmurhash3_32_blocks(bytes: &[u8], h1: u32) -> u32 {
+    const C1: u32 = 0xcc9e2d51u32;
+    const C2: u32 = 0x1b873593u32;
+
+    let mut h1 = h1;
 
     for chunk in bytes.chunks_exact(20) {
-        let mut k1 = u32::from_ne_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]).to_le();
-        let mut k2 = u32::from_ne_bytes([chunk[4], chunk[5], chunk[6], chunk[7]]).to_le();
-        let mut k3 = u32::from_ne_bytes([chunk[8], chunk[9], chunk[10], chunk[11]]).to_le();
-        let mut k4 = u32::from_ne_bytes([chunk[12], chunk[13], chunk[14], chunk[15]]).to_le();
-        let mut k5 = u32::from_ne_bytes([chunk[16], chunk[17], chunk[18], chunk[19]]).to_le();
+        let mut k1 = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        let mut k2 = u32::from_le_bytes([chunk[4], chunk[5], chunk[6], chunk[7]]);
+        let mut k3 = u32::from_le_bytes([chunk[8], chunk[9], chunk[10], chunk[11]]);
+        let mut k4 = u32::from_le_bytes([chunk[12], chunk[13], chunk[14], chunk[15]]);
+        let mut k5 = u32::from_le_bytes([chunk[16], chunk[17], chunk[18], chunk[19]]);
 
         k1 = k1.wrapping_mul(C1);
         k1 = k1.rotate_left(15);
@@ -104,7 +126,7 @@ pub(crate) fn murmurhash3_32(bytes: &[u8], seed: u32) -> u32 {
     let rem = bytes.chunks_exact(20).remainder();
 
     for chunk in rem.chunks_exact(4) {
-        let mut k1 = u32::from_ne_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]).to_le();
+        let mut k1 = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
 
         k1 = k1.wrapping_mul(C1);
         k1 = k1.rotate_left(15);
@@ -116,31 +138,56 @@ pub(crate) fn murmurhash3_32(bytes: &[u8], seed: u32) -> u32 {
         h1 = h1.wrapping_add(0xe6546b64)
     }
 
+    h1
+}
+
+/// Mixes the trailing `tail` (fewer than 4 bytes) into `h1` and applies the
+/// final avalanche, `len` being the total number of hashed bytes.
+#[inline]
+pub(crate) const fn murmurhash3_32_finalize(tail: &[u8], h1: u32, len: usize) -> u32 {
+    const C1: u32 = 0xcc9e2d51u32;
+    const C2: u32 = 0x1b873593u32;
+
+    let mut h1 = h1;
     let mut k1 = 0u32;
 
-    if len & 3 == 3 {
-        k1 ^= (bytes[(block_count * read_size) as usize + 2] as u32) << 16;
+    if tail.len() == 3 {
+        k1 ^= (tail[2] as u32) << 16;
     }
-    if len & 3 >= 2 {
-        k1 ^= (bytes[(block_count * read_size) as usize + 1] as u32) << 8;
+    if tail.len() >= 2 {
+        k1 ^= (tail[1] as u32) << 8;
     }
-    if len & 3 >= 1 {
-        k1 ^= bytes[(block_count * read_size) as usize] as u32;
+    if !tail.is_empty() {
+        k1 ^= tail[0] as u32;
         k1 = k1.wrapping_mul(C1);
         k1 = k1.rotate_left(15);
         k1 = k1.wrapping_mul(C2);
         h1 ^= k1;
     }
 
-    h1 ^= bytes.len() as u32;
+    h1 ^= len as u32;
     h1 = scramble(h1);
 
     h1
 }
 
+/// `const` version of [`murmurhash3_32_with_seed`](crate::murmurhash3_32_with_seed).
+pub const fn murmurhash3_32_const(bytes: &[u8], seed: u32) -> u32 {
+    let blocks = bytes.len() / 4;
+    let mut h1 = seed;
+    let mut i = 0;
+    while i < blocks {
+        let j = i * 4;
+        let k1 = u32::from_le_bytes([bytes[j], bytes[j + 1], bytes[j + 2], bytes[j + 3]]);
+        h1 = murmurhash3_32_round(h1, k1);
+        i += 1;
+    }
+    murmurhash3_32_finalize(bytes.split_at(blocks * 4).1, h1, bytes.len())
+}
+
 #[cfg(test)]
 mod test {
-    use super::murmurhash3_32;
+    use super::{murmurhash3_32, murmurhash3_32_const};
 
     #[test]
     fn test_empty_string() {
@@ -155,6 +202,28 @@ mod test {
         assert_eq!(murmurhash3_32("1234".as_bytes(), 0), 1914461635);
     }
 
+    #[test]
+    fn test_const_matches_runtime() {
+        const EVALUATED: u32 = murmurhash3_32_const(b"The quick brown fox", 0x9747b28c);
+        assert_eq!(
+            EVALUATED,
+            crate::murmurhash3_32_with_seed(b"The quick brown fox", 0x9747b28c)
+        );
+        assert_eq!(crate::mm3h!("The quick brown fox", 0x9747b28c), EVALUATED);
+        assert_eq!(crate::mm3h!("tag"), crate::murmurhash3_32(b"tag"));
+
+        let data: Vec<u8> = (0..300u32).map(|x| (x * 7 + 3) as u8).collect();
+        for seed in [0, 1, 0x9747b28c, u32::MAX] {
+            for len in 0..=data.len() {
+                assert_eq!(
+                    murmurhash3_32_const(&data[..len], seed),
+                    crate::murmurhash3_32_with_seed(&data[..len], seed),
+                    "length {len}, seed {seed}"
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_large_data() {
         assert_eq!(
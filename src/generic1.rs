@@ -0,0 +1,141 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 10/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::generic32::read_le32;
+
+/// Computes the 32-bit **MurmurHash1** hash of the given byte slice using a custom seed.
+///
+/// MurmurHash1 is the original Murmur function. It is kept for compatibility
+/// with existing data keyed by MurmurHash1 values; new code should prefer
+/// MurmurHash3, which has better distribution and collision resistance.
+///
+/// # Parameters
+/// - `bytes`: The input byte slice to hash.
+/// - `seed`: A 32-bit seed value used to initialize the hash state.
+///
+/// # Returns
+/// A 32-bit hash value derived from the input and the seed.
+///
+/// # Notes
+/// - It is **not suitable for cryptographic purposes**.
+pub fn murmurhash1_32_with_seed(bytes: &[u8], seed: u32) -> u32 {
+    const M: u32 = 0xc6a4_a793;
+    const R: u32 = 16;
+
+    let mut h = seed ^ (bytes.len() as u32).wrapping_mul(M);
+
+    for chunk in bytes.chunks_exact(4) {
+        h = h.wrapping_add(read_le32(chunk));
+        h = h.wrapping_mul(M);
+        h ^= h >> 16;
+    }
+
+    let remainder = bytes.chunks_exact(4).remainder();
+    let quot = remainder.len();
+    if quot > 0 {
+        if quot == 3 {
+            h = h.wrapping_add((remainder[2] as u32) << 16);
+        }
+        if quot >= 2 {
+            h = h.wrapping_add((remainder[1] as u32) << 8);
+        }
+        h = h.wrapping_add(remainder[0] as u32);
+        h = h.wrapping_mul(M);
+        h ^= h >> R;
+    }
+
+    h = h.wrapping_mul(M);
+    h ^= h >> 10;
+    h = h.wrapping_mul(M);
+    h ^= h >> 17;
+
+    h
+}
+
+/// Computes the 32-bit **MurmurHash1** hash of the given byte slice.
+///
+/// # Parameters
+/// - `bytes`: The input data to hash.
+///
+/// # Returns
+/// A 32-bit hash value derived from the input.
+pub fn murmurhash1_32(bytes: &[u8]) -> u32 {
+    murmurhash1_32_with_seed(bytes, 0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{murmurhash1_32, murmurhash1_32_with_seed};
+
+    #[test]
+    fn test_empty_string() {
+        assert_eq!(murmurhash1_32("".as_bytes()), 0);
+        assert_eq!(
+            murmurhash1_32_with_seed("".as_bytes(), 0x9747b28c),
+            1260253080
+        );
+    }
+
+    #[test]
+    fn test_tail_lengths() {
+        assert_eq!(murmurhash1_32("1".as_bytes()), 1063085604);
+        assert_eq!(murmurhash1_32("12".as_bytes()), 3083141205);
+        assert_eq!(murmurhash1_32("123".as_bytes()), 2053062691);
+        assert_eq!(murmurhash1_32("1234".as_bytes()), 4046359469);
+        assert_eq!(
+            murmurhash1_32_with_seed("123".as_bytes(), 0x9747b28c),
+            561688391
+        );
+    }
+
+    #[test]
+    fn test_large_data() {
+        assert_eq!(
+            murmurhash1_32("The quick brown fox jumps over the lazy dog".as_bytes()),
+            438640261
+        );
+        assert_eq!(
+            murmurhash1_32(
+                "Rust high performace utilities for YUV format handling and conversion.".as_bytes()
+            ),
+            327509386
+        );
+        assert_eq!(
+            murmurhash1_32_with_seed("432432 gfdsafgsd 32432 fds".as_bytes(), 0x9747b28c),
+            863180360
+        );
+        assert_eq!(murmurhash1_32("MurmurHash2 (32-bit, x86)—The original version; contains a flaw that weakens collision in some cases.[9]
+MurmurHash2A (32-bit, x86)—A fixed variant using Merkle–Damgård construction. Slightly slower.
+CMurmurHash2A (32-bit, x86)—MurmurHash2A, but works incrementally.
+MurmurHashNeutral2 (32-bit, x86)—Slower, but endian- and alignment-neutral.
+MurmurHashAligned2 (32-bit, x86)—Slower, but does aligned reads (safer on some platforms).
+MurmurHash64A (64-bit, x64)—The original 64-bit version. Optimized for 64-bit arithmetic.
+MurmurHash64B (64-bit, x86)—A 64-bit version optimized for 32-bit platforms. It is not a true 64-bit hash due to insufficient mixing of the stripes.[10]
+".as_bytes()), 2331502944);
+    }
+}
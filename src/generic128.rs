@@ -27,9 +27,10 @@
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 use crate::generic64::read_le64;
+use std::hash::Hasher;
 
 #[inline]
-fn fmix64(k: u64) -> u64 {
+pub(crate) const fn fmix64(k: u64) -> u64 {
     const C1: u64 = 0xff51_afd7_ed55_8ccd;
     const C2: u64 = 0xc4ce_b9fe_1a85_ec53;
     const R: u32 = 33;
@@ -42,37 +43,69 @@ fn fmix64(k: u64) -> u64 {
     tmp
 }
 
-#[inline]
-fn murmurhash3_128_with_seed_impl(bytes: &[u8], seed: u32) -> u128 {
-    const C1: u64 = 0x87c3_7b91_1142_53d5;
-    const C2: u64 = 0x4cf5_ad43_2745_937f;
+pub(crate) const C1: u64 = 0x87c3_7b91_1142_53d5;
+pub(crate) const C2: u64 = 0x4cf5_ad43_2745_937f;
+
+/// Folds the already pre-mixed `k1`/`k2` of one 16-byte block into the state.
+#[inline(always)]
+pub(crate) const fn murmurhash3_128_round(h1: u64, h2: u64, k1: u64, k2: u64) -> (u64, u64) {
     const C3: u64 = 0x52dc_e729;
     const C4: u64 = 0x3849_5ab5;
     const R1: u32 = 27;
-    const R3: u32 = 33;
     const M: u64 = 5;
-    let mut h1: u64 = seed as u64;
-    let mut h2: u64 = seed as u64;
+
+    let mut h1 = h1 ^ k1;
+    h1 = h1
+        .rotate_left(R1)
+        .wrapping_add(h2)
+        .wrapping_mul(M)
+        .wrapping_add(C3);
+    let mut h2 = h2 ^ k2;
+    h2 = h2
+        .rotate_left(31)
+        .wrapping_add(h1)
+        .wrapping_mul(M)
+        .wrapping_add(C4);
+    (h1, h2)
+}
+
+#[inline]
+pub(crate) fn murmurhash3_128_blocks(bytes: &[u8], mut h1: u64, mut h2: u64) -> (u64, u64) {
+    const R3: u32 = 33;
 
     for chunk in bytes.chunks_exact(16) {
         let k1 = read_le64(chunk);
         let k2 = read_le64(&chunk[8..16]);
-        h1 ^= k1.wrapping_mul(C1).rotate_left(31).wrapping_mul(C2);
-        h1 = h1
-            .rotate_left(R1)
-            .wrapping_add(h2)
-            .wrapping_mul(M)
-            .wrapping_add(C3);
-        h2 ^= k2.wrapping_mul(C2).rotate_left(R3).wrapping_mul(C1);
-        h2 = h2
-            .rotate_left(31)
-            .wrapping_add(h1)
-            .wrapping_mul(M)
-            .wrapping_add(C4);
-    }
-
-    let remainder = bytes.chunks_exact(16).remainder();
+        (h1, h2) = murmurhash3_128_round(
+            h1,
+            h2,
+            k1.wrapping_mul(C1).rotate_left(31).wrapping_mul(C2),
+            k2.wrapping_mul(C2).rotate_left(R3).wrapping_mul(C1),
+        );
+    }
+
+    (h1, h2)
+}
+
+fn murmurhash3_128_dispatch(bytes: &[u8], h1: u64, h2: u64) -> (u64, u64) {
+    #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+    {
+        use crate::avx512::avx512_murmurhash3_128_blocks;
+        use crate::backend::{Backend, active_backend};
+        if active_backend().allows(Backend::Avx512) {
+            return unsafe { avx512_murmurhash3_128_blocks(bytes, h1, h2) };
+        }
+    }
+    murmurhash3_128_blocks(bytes, h1, h2)
+}
 
+#[inline]
+const fn murmurhash3_128_finalize(
+    remainder: &[u8],
+    mut h1: u64,
+    mut h2: u64,
+    total: usize,
+) -> u128 {
     if !remainder.is_empty() {
         let len = remainder.len();
 
@@ -131,8 +164,8 @@ fn murmurhash3_128_with_seed_impl(bytes: &[u8], seed: u32) -> u128 {
         h1 ^= k1;
     }
 
-    h1 ^= bytes.len() as u64;
-    h2 ^= bytes.len() as u64;
+    h1 ^= total as u64;
+    h2 ^= total as u64;
     h1 = h1.wrapping_add(h2);
     h2 = h2.wrapping_add(h1);
     h1 = fmix64(h1);
@@ -142,6 +175,12 @@ fn murmurhash3_128_with_seed_impl(bytes: &[u8], seed: u32) -> u128 {
     ((h2 as u128) << 64) | (h1 as u128)
 }
 
+#[inline]
+fn murmurhash3_128_with_seed_impl(bytes: &[u8], seed: u32) -> u128 {
+    let (h1, h2) = murmurhash3_128_dispatch(bytes, seed as u64, seed as u64);
+    murmurhash3_128_finalize(bytes.chunks_exact(16).remainder(), h1, h2, bytes.len())
+}
+
 /// Computes the 128-bit MurmurHash3 of a byte slice with a seed.
 ///
 /// This is a fast, non-cryptographic hash function suitable for hash tables,
@@ -160,6 +199,47 @@ pub fn murmurhash3_128_with_seed(bytes: &[u8], seed: u32) -> u128 {
     murmurhash3_128_with_seed_impl(bytes, seed)
 }
 
+/// `const` version of [`murmurhash3_128_with_seed`].
+pub const fn murmurhash3_128_const(bytes: &[u8], seed: u32) -> u128 {
+    const R3: u32 = 33;
+
+    let blocks = bytes.len() / 16;
+    let mut h1 = seed as u64;
+    let mut h2 = seed as u64;
+    let mut i = 0;
+    while i < blocks {
+        let j = i * 16;
+        let k1 = u64::from_le_bytes([
+            bytes[j],
+            bytes[j + 1],
+            bytes[j + 2],
+            bytes[j + 3],
+            bytes[j + 4],
+            bytes[j + 5],
+            bytes[j + 6],
+            bytes[j + 7],
+        ]);
+        let k2 = u64::from_le_bytes([
+            bytes[j + 8],
+            bytes[j + 9],
+            bytes[j + 10],
+            bytes[j + 11],
+            bytes[j + 12],
+            bytes[j + 13],
+            bytes[j + 14],
+            bytes[j + 15],
+        ]);
+        (h1, h2) = murmurhash3_128_round(
+            h1,
+            h2,
+            k1.wrapping_mul(C1).rotate_left(31).wrapping_mul(C2),
+            k2.wrapping_mul(C2).rotate_left(R3).wrapping_mul(C1),
+        );
+        i += 1;
+    }
+    murmurhash3_128_finalize(bytes.split_at(blocks * 16).1, h1, h2, bytes.len())
+}
+
 /// Computes the 128-bit MurmurHash3 of a byte slice using a default seed of 0.
 ///
 /// This is a fast, non-cryptographic hash function suitable for hash tables,
@@ -176,10 +256,102 @@ pub fn murmurhash3_128(bytes: &[u8]) -> u128 {
     murmurhash3_128_with_seed_impl(bytes, 0)
 }
 
+/// A streaming implementation of the **MurmurHash3 (x64_128)** algorithm.
+///
+/// Data may be fed in arbitrary chunks with [`Murmur3x64_128::update`];
+/// complete 16-byte blocks are mixed immediately and up to 15 bytes are
+/// carried over to the next call. [`Murmur3x64_128::finalize`] yields exactly
+/// the same value as [`murmurhash3_128_with_seed`] over the concatenated input.
+///
+/// As a [`Hasher`], [`Hasher::finish`] returns the low 64 bits of the 128-bit hash.
+#[derive(Clone, Debug)]
+pub struct Murmur3x64_128 {
+    h1: u64,
+    h2: u64,
+    buffer: [u8; 16],
+    buffer_len: usize,
+    len: usize,
+}
+
+impl Murmur3x64_128 {
+    /// Creates a new [`Murmur3x64_128`] with a default seed value of `0`.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new [`Murmur3x64_128`] initialized with the specified seed.
+    ///
+    /// # Parameters
+    /// - `seed`: A 32-bit integer used to initialize both hash lanes.
+    pub fn new_with_seed(seed: u32) -> Self {
+        Self {
+            h1: seed as u64,
+            h2: seed as u64,
+            buffer: [0; 16],
+            buffer_len: 0,
+            len: 0,
+        }
+    }
+
+    /// Feeds the next chunk of input into the hash state.
+    #[inline]
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.len = self.len.wrapping_add(bytes.len());
+        let mut bytes = bytes;
+
+        if self.buffer_len != 0 {
+            let take = (16 - self.buffer_len).min(bytes.len());
+            self.buffer[self.buffer_len..self.buffer_len + take].copy_from_slice(&bytes[..take]);
+            self.buffer_len += take;
+            bytes = &bytes[take..];
+            if self.buffer_len < 16 {
+                return;
+            }
+            (self.h1, self.h2) = murmurhash3_128_blocks(&self.buffer, self.h1, self.h2);
+            self.buffer_len = 0;
+        }
+
+        let full = bytes.len() & !15;
+        if full != 0 {
+            (self.h1, self.h2) = murmurhash3_128_dispatch(&bytes[..full], self.h1, self.h2);
+        }
+
+        let rem = &bytes[full..];
+        self.buffer[..rem.len()].copy_from_slice(rem);
+        self.buffer_len = rem.len();
+    }
+
+    /// Returns the 128-bit hash of all bytes fed so far.
+    ///
+    /// The state is not consumed, so more data may be appended afterwards.
+    pub fn finalize(&self) -> u128 {
+        murmurhash3_128_finalize(&self.buffer[..self.buffer_len], self.h1, self.h2, self.len)
+    }
+}
+
+impl Default for Murmur3x64_128 {
+    fn default() -> Self {
+        Self::new_with_seed(0)
+    }
+}
+
+impl Hasher for Murmur3x64_128 {
+    fn finish(&self) -> u64 {
+        self.finalize() as u64
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    le_integer_writes!();
+}
+
 #[cfg(test)]
 mod test {
-    use super::murmurhash3_128_with_seed;
+    use super::{Murmur3x64_128, murmurhash3_128_const, murmurhash3_128_with_seed};
     use crate::murmurhash3_128;
+    use std::hash::Hasher;
 
     #[test]
     fn test_empty_string() {
@@ -206,6 +378,26 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_const_matches_runtime() {
+        const EVALUATED: u128 = murmurhash3_128_const(b"The quick brown fox", 0x9747b28c);
+        assert_eq!(
+            EVALUATED,
+            murmurhash3_128_with_seed(b"The quick brown fox", 0x9747b28c)
+        );
+
+        let data: Vec<u8> = (0..300u32).map(|x| (x * 7 + 3) as u8).collect();
+        for seed in [0, 1, 0x9747b28c, u32::MAX] {
+            for len in 0..=data.len() {
+                assert_eq!(
+                    murmurhash3_128_const(&data[..len], seed),
+                    murmurhash3_128_with_seed(&data[..len], seed),
+                    "length {len}, seed {seed}"
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_large_data() {
         assert_eq!(
@@ -220,4 +412,26 @@ mod test {
         );
         assert_eq!(murmurhash3_128("MurmurHash2 (32-bit, x86)—The original version; contains a flaw that weakens collision in some cases.[9]MurmurHash2A (32-bit, x86)—A fixed variant using Merkle–Damgård construction. Slightly slower.".as_bytes()), 285565187209700280420403743997782857281);
     }
+
+    #[test]
+    fn test_streaming_splits() {
+        let data: Vec<u8> = (0..200u32).map(|x| (x * 13 + 5) as u8).collect();
+        for len in [0usize, 1, 8, 15, 16, 17, 31, 32, 33, 100, 200] {
+            let expected = murmurhash3_128_with_seed(&data[..len], 42);
+            for split in 1..=17 {
+                let mut state = Murmur3x64_128::new_with_seed(42);
+                for chunk in data[..len].chunks(split) {
+                    state.update(chunk);
+                }
+                assert_eq!(state.finalize(), expected);
+                assert_eq!(state.finish(), expected as u64);
+            }
+            for cut in 0..=len {
+                let mut state = Murmur3x64_128::new_with_seed(42);
+                state.update(&data[..cut]);
+                state.update(&data[cut..len]);
+                assert_eq!(state.finalize(), expected);
+            }
+        }
+    }
 }
@@ -0,0 +1,421 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 10/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::generic::scramble;
+use crate::generic32::read_le32;
+use std::hash::Hasher;
+
+pub(crate) const C1: u32 = 0x239b_961b;
+pub(crate) const C2: u32 = 0xab0e_9789;
+pub(crate) const C3: u32 = 0x38b3_4ae5;
+pub(crate) const C4: u32 = 0xa1e3_8b93;
+
+/// Folds four already pre-mixed `k` lanes of one 16-byte block into the state.
+#[inline(always)]
+pub(crate) fn murmurhash3_x86_128_round(h: [u32; 4], k: [u32; 4]) -> [u32; 4] {
+    let [mut h1, mut h2, mut h3, mut h4] = h;
+
+    h1 ^= k[0];
+    h1 = h1.rotate_left(19);
+    h1 = h1.wrapping_add(h2);
+    h1 = h1.wrapping_mul(5).wrapping_add(0x561c_cd1b);
+
+    h2 ^= k[1];
+    h2 = h2.rotate_left(17);
+    h2 = h2.wrapping_add(h3);
+    h2 = h2.wrapping_mul(5).wrapping_add(0x0bca_a747);
+
+    h3 ^= k[2];
+    h3 = h3.rotate_left(15);
+    h3 = h3.wrapping_add(h4);
+    h3 = h3.wrapping_mul(5).wrapping_add(0x96cd_1c35);
+
+    h4 ^= k[3];
+    h4 = h4.rotate_left(13);
+    h4 = h4.wrapping_add(h1);
+    h4 = h4.wrapping_mul(5).wrapping_add(0x32ac_3b17);
+
+    [h1, h2, h3, h4]
+}
+
+pub(crate) fn murmurhash3_x86_128_blocks(bytes: &[u8], h: [u32; 4]) -> [u32; 4] {
+    let mut h = h;
+
+    for chunk in bytes.chunks_exact(16) {
+        let mut k1 = read_le32(chunk);
+        let mut k2 = read_le32(&chunk[4..8]);
+        let mut k3 = read_le32(&chunk[8..12]);
+        let mut k4 = read_le32(&chunk[12..16]);
+
+        k1 = k1.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2);
+        k2 = k2.wrapping_mul(C2).rotate_left(16).wrapping_mul(C3);
+        k3 = k3.wrapping_mul(C3).rotate_left(17).wrapping_mul(C4);
+        k4 = k4.wrapping_mul(C4).rotate_left(18).wrapping_mul(C1);
+
+        h = murmurhash3_x86_128_round(h, [k1, k2, k3, k4]);
+    }
+
+    h
+}
+
+#[inline]
+pub(crate) fn murmurhash3_x86_128_finalize(remainder: &[u8], h: [u32; 4], total: usize) -> u128 {
+    let [mut h1, mut h2, mut h3, mut h4] = h;
+    let len = remainder.len();
+
+    let mut k1 = 0u32;
+    let mut k2 = 0u32;
+    let mut k3 = 0u32;
+    let mut k4 = 0u32;
+
+    if len >= 15 {
+        k4 ^= (remainder[14] as u32) << 16;
+    }
+    if len >= 14 {
+        k4 ^= (remainder[13] as u32) << 8;
+    }
+    if len >= 13 {
+        k4 ^= remainder[12] as u32;
+        k4 = k4.wrapping_mul(C4).rotate_left(18).wrapping_mul(C1);
+        h4 ^= k4;
+    }
+    if len >= 12 {
+        k3 ^= (remainder[11] as u32) << 24;
+    }
+    if len >= 11 {
+        k3 ^= (remainder[10] as u32) << 16;
+    }
+    if len >= 10 {
+        k3 ^= (remainder[9] as u32) << 8;
+    }
+    if len >= 9 {
+        k3 ^= remainder[8] as u32;
+        k3 = k3.wrapping_mul(C3).rotate_left(17).wrapping_mul(C4);
+        h3 ^= k3;
+    }
+    if len >= 8 {
+        k2 ^= (remainder[7] as u32) << 24;
+    }
+    if len >= 7 {
+        k2 ^= (remainder[6] as u32) << 16;
+    }
+    if len >= 6 {
+        k2 ^= (remainder[5] as u32) << 8;
+    }
+    if len >= 5 {
+        k2 ^= remainder[4] as u32;
+        k2 = k2.wrapping_mul(C2).rotate_left(16).wrapping_mul(C3);
+        h2 ^= k2;
+    }
+    if len >= 4 {
+        k1 ^= (remainder[3] as u32) << 24;
+    }
+    if len >= 3 {
+        k1 ^= (remainder[2] as u32) << 16;
+    }
+    if len >= 2 {
+        k1 ^= (remainder[1] as u32) << 8;
+    }
+    if len >= 1 {
+        k1 ^= remainder[0] as u32;
+        k1 = k1.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2);
+        h1 ^= k1;
+    }
+
+    h1 ^= total as u32;
+    h2 ^= total as u32;
+    h3 ^= total as u32;
+    h4 ^= total as u32;
+
+    h1 = h1.wrapping_add(h2).wrapping_add(h3).wrapping_add(h4);
+    h2 = h2.wrapping_add(h1);
+    h3 = h3.wrapping_add(h1);
+    h4 = h4.wrapping_add(h1);
+
+    h1 = scramble(h1);
+    h2 = scramble(h2);
+    h3 = scramble(h3);
+    h4 = scramble(h4);
+
+    h1 = h1.wrapping_add(h2).wrapping_add(h3).wrapping_add(h4);
+    h2 = h2.wrapping_add(h1);
+    h3 = h3.wrapping_add(h1);
+    h4 = h4.wrapping_add(h1);
+
+    ((h4 as u128) << 96) | ((h3 as u128) << 64) | ((h2 as u128) << 32) | (h1 as u128)
+}
+
+fn murmurhash3_x86_128_dispatch(bytes: &[u8], h: [u32; 4]) -> [u32; 4] {
+    #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+    {
+        use crate::backend::{Backend, active_backend};
+        use crate::neon::neon_murmurhash3_x86_128_blocks;
+        if active_backend() == Backend::Neon {
+            return neon_murmurhash3_x86_128_blocks(bytes, h);
+        }
+    }
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx"))]
+    {
+        use crate::avx::avx_murmurhash3_x86_128_blocks;
+        use crate::backend::{Backend, active_backend};
+        if active_backend().allows(Backend::Avx2) {
+            return unsafe { avx_murmurhash3_x86_128_blocks(bytes, h) };
+        }
+    }
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "sse"))]
+    {
+        use crate::backend::{Backend, active_backend};
+        use crate::sse::sse_murmurhash3_x86_128_blocks;
+        if active_backend().allows(Backend::Sse41) {
+            return unsafe { sse_murmurhash3_x86_128_blocks(bytes, h) };
+        }
+    }
+    murmurhash3_x86_128_blocks(bytes, h)
+}
+
+/// Computes the 128-bit **MurmurHash3 x86_128** hash of a byte slice with a seed.
+///
+/// This variant mixes four independent 32-bit lanes and is therefore well suited
+/// for 32-bit targets. Its output differs from [`crate::murmurhash3_128_with_seed`]
+/// (the x64_128 variant) for the same input.
+///
+/// # Parameters
+///
+/// - `bytes`: The input data to hash as a byte slice (`&[u8]`).
+/// - `seed`: A 32-bit seed used to initialize all four lanes.
+///
+/// # Returns
+///
+/// A 128-bit hash (`u128`) whose little-endian bytes match the output buffer of
+/// the reference `MurmurHash3_x86_128`: `h1` occupies the lowest 32 bits and `h4`
+/// the highest.
+pub fn murmurhash3_x86_128_with_seed(bytes: &[u8], seed: u32) -> u128 {
+    let h = murmurhash3_x86_128_dispatch(bytes, [seed; 4]);
+    murmurhash3_x86_128_finalize(bytes.chunks_exact(16).remainder(), h, bytes.len())
+}
+
+/// Computes the 128-bit **MurmurHash3 x86_128** hash of a byte slice using a seed of 0.
+///
+/// # Parameters
+///
+/// - `bytes`: The input data to hash as a byte slice (`&[u8]`).
+///
+/// # Returns
+///
+/// A 128-bit hash (`u128`) of the input data.
+pub fn murmurhash3_x86_128(bytes: &[u8]) -> u128 {
+    murmurhash3_x86_128_with_seed(bytes, 0)
+}
+
+/// A streaming implementation of the **MurmurHash3 (x86_128)** algorithm.
+///
+/// Data may be fed in arbitrary chunks with [`Murmur3x86_128::update`];
+/// complete 16-byte blocks are mixed into the four lanes immediately and up
+/// to 15 bytes are carried over to the next call. [`Murmur3x86_128::finalize`]
+/// yields exactly the same value as [`murmurhash3_x86_128_with_seed`] over the
+/// concatenated input.
+///
+/// As a [`Hasher`], [`Hasher::finish`] returns the low 64 bits of the 128-bit hash.
+#[derive(Clone, Debug)]
+pub struct Murmur3x86_128 {
+    h: [u32; 4],
+    buffer: [u8; 16],
+    buffer_len: usize,
+    len: usize,
+}
+
+impl Murmur3x86_128 {
+    /// Creates a new [`Murmur3x86_128`] with a default seed value of `0`.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new [`Murmur3x86_128`] initialized with the specified seed.
+    ///
+    /// # Parameters
+    /// - `seed`: A 32-bit integer used to initialize all four lanes.
+    pub fn new_with_seed(seed: u32) -> Self {
+        Self {
+            h: [seed; 4],
+            buffer: [0; 16],
+            buffer_len: 0,
+            len: 0,
+        }
+    }
+
+    /// Feeds the next chunk of input into the hash state.
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.len = self.len.wrapping_add(bytes.len());
+        let mut bytes = bytes;
+
+        if self.buffer_len != 0 {
+            let take = (16 - self.buffer_len).min(bytes.len());
+            self.buffer[self.buffer_len..self.buffer_len + take].copy_from_slice(&bytes[..take]);
+            self.buffer_len += take;
+            bytes = &bytes[take..];
+            if self.buffer_len < 16 {
+                return;
+            }
+            self.h = murmurhash3_x86_128_blocks(&self.buffer, self.h);
+            self.buffer_len = 0;
+        }
+
+        let full = bytes.len() & !15;
+        if full != 0 {
+            self.h = murmurhash3_x86_128_dispatch(&bytes[..full], self.h);
+        }
+
+        let rem = &bytes[full..];
+        self.buffer[..rem.len()].copy_from_slice(rem);
+        self.buffer_len = rem.len();
+    }
+
+    /// Returns the 128-bit hash of all bytes fed so far.
+    ///
+    /// The state is not consumed, so more data may be appended afterwards.
+    pub fn finalize(&self) -> u128 {
+        murmurhash3_x86_128_finalize(&self.buffer[..self.buffer_len], self.h, self.len)
+    }
+}
+
+impl Default for Murmur3x86_128 {
+    fn default() -> Self {
+        Self::new_with_seed(0)
+    }
+}
+
+impl Hasher for Murmur3x86_128 {
+    fn finish(&self) -> u64 {
+        self.finalize() as u64
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    le_integer_writes!();
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        Murmur3x86_128, murmurhash3_x86_128, murmurhash3_x86_128_blocks,
+        murmurhash3_x86_128_finalize, murmurhash3_x86_128_with_seed,
+    };
+    use std::hash::Hasher;
+
+    fn generic_x86_128(bytes: &[u8], seed: u32) -> u128 {
+        let h = murmurhash3_x86_128_blocks(bytes, [seed; 4]);
+        murmurhash3_x86_128_finalize(bytes.chunks_exact(16).remainder(), h, bytes.len())
+    }
+
+    #[test]
+    fn test_empty_string() {
+        assert_eq!(generic_x86_128("".as_bytes(), 0), 0);
+        assert_eq!(
+            generic_x86_128("".as_bytes(), 0x9747b28c),
+            0x5b576a1c5b576a1c5b576a1cf7bed5a1
+        );
+    }
+
+    #[test]
+    fn test_tail_lengths() {
+        assert_eq!(
+            generic_x86_128("1".as_bytes(), 0),
+            0xd96bb1d5d96bb1d5d96bb1d5e0f2f4fb
+        );
+        assert_eq!(
+            generic_x86_128("12".as_bytes(), 0),
+            0xaf4f491eaf4f491eaf4f491ec09b1f9f
+        );
+        assert_eq!(
+            generic_x86_128("123".as_bytes(), 0),
+            0x7b406f087b406f087b406f08514d2b2f
+        );
+        assert_eq!(
+            generic_x86_128("1234".as_bytes(), 0),
+            0x2eaa32ce2eaa32ce2eaa32ce4adf644d
+        );
+        assert_eq!(
+            generic_x86_128("1".as_bytes(), 0x9747b28c),
+            0xe2a5ef3de2a5ef3de2a5ef3dc1b9dfcb
+        );
+    }
+
+    #[test]
+    fn test_large_data() {
+        assert_eq!(
+            generic_x86_128("The quick brown fox jumps over the lazy dog".as_bytes(), 0),
+            0xe5e91d2c5d7bf66cecee2c672f1583c3
+        );
+        assert_eq!(
+            generic_x86_128(
+                "Rust high performace utilities for YUV format handling and conversion.".as_bytes(),
+                0
+            ),
+            0x319a8ce473555480f606e02c955eea09
+        );
+        assert_eq!(
+            generic_x86_128("432432 gfdsafgsd 32432 fds".as_bytes(), 0x9747b28c),
+            0x787bf640161cd813ca7ce27b04ef177c
+        );
+    }
+
+    #[test]
+    fn test_dispatch_matches_generic() {
+        let data: Vec<u8> = (0..300u32).map(|x| (x * 11 + 7) as u8).collect();
+        for len in 0..data.len() {
+            assert_eq!(
+                murmurhash3_x86_128(&data[..len]),
+                generic_x86_128(&data[..len], 0)
+            );
+        }
+    }
+
+    #[test]
+    fn test_streaming_splits() {
+        let data: Vec<u8> = (0..200u32).map(|x| (x * 13 + 5) as u8).collect();
+        for len in [0usize, 1, 8, 15, 16, 17, 31, 32, 33, 100, 200] {
+            let expected = murmurhash3_x86_128_with_seed(&data[..len], 42);
+            for split in 1..=17 {
+                let mut state = Murmur3x86_128::new_with_seed(42);
+                for chunk in data[..len].chunks(split) {
+                    state.update(chunk);
+                }
+                assert_eq!(state.finalize(), expected);
+                assert_eq!(state.finish(), expected as u64);
+            }
+            for cut in 0..=len {
+                let mut state = Murmur3x86_128::new_with_seed(42);
+                state.update(&data[..cut]);
+                state.update(&data[cut..len]);
+                assert_eq!(state.finalize(), expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,480 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 10/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+pub(crate) const M: u32 = 0x5bd1_e995;
+
+#[inline(always)]
+pub(crate) fn read_le32(bytes: &[u8]) -> u32 {
+    u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+#[inline(always)]
+pub(crate) fn mmix(h: u32, mut k: u32) -> u32 {
+    k = k.wrapping_mul(M);
+    k ^= k >> 24;
+    k = k.wrapping_mul(M);
+    h.wrapping_mul(M) ^ k
+}
+
+#[inline(always)]
+fn fmix(mut h: u32) -> u32 {
+    h ^= h >> 13;
+    h = h.wrapping_mul(M);
+    h ^= h >> 15;
+    h
+}
+
+#[inline(always)]
+pub(crate) fn read_tail(remainder: &[u8]) -> u32 {
+    let mut t = 0u32;
+    if remainder.len() == 3 {
+        t ^= (remainder[2] as u32) << 16;
+    }
+    if remainder.len() >= 2 {
+        t ^= (remainder[1] as u32) << 8;
+    }
+    if !remainder.is_empty() {
+        t ^= remainder[0] as u32;
+    }
+    t
+}
+
+#[allow(dead_code)]
+pub(crate) fn murmurhash2_32_blocks(bytes: &[u8], mut h: u32) -> u32 {
+    for chunk in bytes.chunks_exact(16) {
+        let mut k0 = read_le32(chunk);
+        let mut k1 = read_le32(&chunk[4..8]);
+        let mut k2 = read_le32(&chunk[8..12]);
+        let mut k3 = read_le32(&chunk[12..16]);
+
+        k0 = k0.wrapping_mul(M);
+        k0 ^= k0 >> 24;
+        k0 = k0.wrapping_mul(M);
+
+        k1 = k1.wrapping_mul(M);
+        k1 ^= k1 >> 24;
+        k1 = k1.wrapping_mul(M);
+
+        k2 = k2.wrapping_mul(M);
+        k2 ^= k2 >> 24;
+        k2 = k2.wrapping_mul(M);
+
+        k3 = k3.wrapping_mul(M);
+        k3 ^= k3 >> 24;
+        k3 = k3.wrapping_mul(M);
+
+        h = h.wrapping_mul(M) ^ k0;
+        h = h.wrapping_mul(M) ^ k1;
+        h = h.wrapping_mul(M) ^ k2;
+        h = h.wrapping_mul(M) ^ k3;
+    }
+
+    let rem = bytes.chunks_exact(16).remainder();
+
+    for chunk in rem.chunks_exact(4) {
+        h = mmix(h, read_le32(chunk));
+    }
+
+    h
+}
+
+pub(crate) fn murmurhash2_32_dispatch(bytes: &[u8], h: u32) -> u32 {
+    #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+    {
+        use crate::backend::{Backend, active_backend};
+        use crate::neon::neon_murmurhash2_32_blocks;
+        if active_backend() == Backend::Neon {
+            return neon_murmurhash2_32_blocks(bytes, h);
+        }
+    }
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx"))]
+    {
+        use crate::avx::avx_murmurhash2_32_blocks;
+        use crate::backend::{Backend, active_backend};
+        if active_backend().allows(Backend::Avx2) {
+            return unsafe { avx_murmurhash2_32_blocks(bytes, h) };
+        }
+    }
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "sse"))]
+    {
+        use crate::backend::{Backend, active_backend};
+        use crate::sse::sse_murmurhash2_32_blocks;
+        if active_backend().allows(Backend::Sse41) {
+            return unsafe { sse_murmurhash2_32_blocks(bytes, h) };
+        }
+    }
+    murmurhash2_32_blocks(bytes, h)
+}
+
+/// Computes the 32-bit **MurmurHash2** hash of the given byte slice using a custom seed.
+///
+/// This is the original 32-bit MurmurHash2 (`m = 0x5bd1e995`, `r = 24`) as used by
+/// Kafka, nginx, libmemcached and many other systems. Input is read as little-endian
+/// 32-bit words, so the result is the same on every target.
+///
+/// # Parameters
+/// - `bytes`: The input byte slice to hash.
+/// - `seed`: A 32-bit seed value used to initialize the hash state.
+///
+/// # Returns
+/// A 32-bit hash value derived from the input and the seed.
+///
+/// # Notes
+/// - MurmurHash2 has a known flaw that weakens collision resistance for some
+///   inputs; prefer [`murmurhash2a_32_with_seed`] or MurmurHash3 for new data.
+/// - It is **not suitable for cryptographic purposes**.
+pub fn murmurhash2_32_with_seed(bytes: &[u8], seed: u32) -> u32 {
+    let mut h = murmurhash2_32_dispatch(bytes, seed ^ bytes.len() as u32);
+
+    let remainder = bytes.chunks_exact(4).remainder();
+    if !remainder.is_empty() {
+        h ^= read_tail(remainder);
+        h = h.wrapping_mul(M);
+    }
+
+    fmix(h)
+}
+
+/// Computes the 32-bit **MurmurHash2** hash of the given byte slice.
+///
+/// # Parameters
+/// - `bytes`: The input data to hash.
+///
+/// # Returns
+/// A 32-bit hash value derived from the input.
+pub fn murmurhash2_32(bytes: &[u8]) -> u32 {
+    murmurhash2_32_with_seed(bytes, 0)
+}
+
+/// Mixes the tail word and the total length into `h` and applies the
+/// MurmurHash2A finalizer.
+#[inline]
+pub(crate) fn murmurhash2a_32_finalize(tail: u32, h: u32, len: usize) -> u32 {
+    let h = mmix(h, tail);
+    let h = mmix(h, len as u32);
+    fmix(h)
+}
+
+/// Computes the 32-bit **MurmurHash2A** hash of the given byte slice using a custom seed.
+///
+/// MurmurHash2A is a fixed variant of MurmurHash2 built on the Merkle–Damgård
+/// construction: the tail and the length are mixed in as ordinary blocks,
+/// which removes the MurmurHash2 flaw at a slight speed cost.
+///
+/// # Parameters
+/// - `bytes`: The input byte slice to hash.
+/// - `seed`: A 32-bit seed value used to initialize the hash state.
+///
+/// # Returns
+/// A 32-bit hash value derived from the input and the seed.
+pub fn murmurhash2a_32_with_seed(bytes: &[u8], seed: u32) -> u32 {
+    let h = murmurhash2_32_dispatch(bytes, seed);
+    murmurhash2a_32_finalize(read_tail(bytes.chunks_exact(4).remainder()), h, bytes.len())
+}
+
+/// Computes the 32-bit **MurmurHash2A** hash of the given byte slice.
+///
+/// # Parameters
+/// - `bytes`: The input data to hash.
+///
+/// # Returns
+/// A 32-bit hash value derived from the input.
+pub fn murmurhash2a_32(bytes: &[u8]) -> u32 {
+    murmurhash2a_32_with_seed(bytes, 0)
+}
+
+/// Computes the 32-bit **MurmurHashNeutral2** hash of the given byte slice using a custom seed.
+///
+/// MurmurHashNeutral2 is the endian- and alignment-neutral form of MurmurHash2:
+/// every block is assembled from individual bytes instead of a word load, and no
+/// SIMD kernel is involved. It yields the same values as the reference
+/// implementation on every target, little- or big-endian, and equals
+/// [`murmurhash2_32_with_seed`] for the same input.
+///
+/// # Parameters
+/// - `bytes`: The input byte slice to hash.
+/// - `seed`: A 32-bit seed value used to initialize the hash state.
+///
+/// # Returns
+/// A 32-bit hash value derived from the input and the seed.
+pub fn murmurhash_neutral2_with_seed(bytes: &[u8], seed: u32) -> u32 {
+    let mut h = seed ^ bytes.len() as u32;
+
+    for chunk in bytes.chunks_exact(4) {
+        let k = (chunk[0] as u32)
+            | ((chunk[1] as u32) << 8)
+            | ((chunk[2] as u32) << 16)
+            | ((chunk[3] as u32) << 24);
+        h = mmix(h, k);
+    }
+
+    let remainder = bytes.chunks_exact(4).remainder();
+    if !remainder.is_empty() {
+        h ^= read_tail(remainder);
+        h = h.wrapping_mul(M);
+    }
+
+    fmix(h)
+}
+
+/// Computes the 32-bit **MurmurHashNeutral2** hash of the given byte slice.
+///
+/// # Parameters
+/// - `bytes`: The input data to hash.
+///
+/// # Returns
+/// A 32-bit hash value derived from the input.
+pub fn murmurhash_neutral2(bytes: &[u8]) -> u32 {
+    murmurhash_neutral2_with_seed(bytes, 0)
+}
+
+#[inline(always)]
+fn read_aligned_le32(chunk: &[u8]) -> u32 {
+    debug_assert!(chunk.len() >= 4 && chunk.as_ptr().cast::<u32>().is_aligned());
+    // SAFETY: the caller guarantees at least 4 readable bytes at a 4-byte aligned address.
+    u32::from_le(unsafe { chunk.as_ptr().cast::<u32>().read() })
+}
+
+/// Computes the 32-bit **MurmurHashAligned2** hash of the given byte slice using a custom seed.
+///
+/// MurmurHashAligned2 only performs 4-byte aligned word loads. When the input
+/// does not start on a word boundary, the leading bytes are kept in a carry
+/// register and every block is stitched together from two neighbouring aligned
+/// words, which makes it safe on platforms that trap on misaligned access.
+/// Words are interpreted as little-endian, so the result equals
+/// [`murmurhash2_32_with_seed`] and [`murmurhash_neutral2_with_seed`] on every target.
+///
+/// # Parameters
+/// - `bytes`: The input byte slice to hash.
+/// - `seed`: A 32-bit seed value used to initialize the hash state.
+///
+/// # Returns
+/// A 32-bit hash value derived from the input and the seed.
+pub fn murmurhash_aligned2_with_seed(bytes: &[u8], seed: u32) -> u32 {
+    let mut h = seed ^ bytes.len() as u32;
+    let align = bytes.as_ptr() as usize & 3;
+
+    if align != 0 && bytes.len() >= 4 {
+        let sl = 8 * (4 - align) as u32;
+        let sr = 8 * align as u32;
+
+        let (head, rest) = bytes.split_at(4 - align);
+        let mut t = read_tail(head) << sr;
+
+        for chunk in rest.chunks_exact(4) {
+            let d = read_aligned_le32(chunk);
+            h = mmix(h, (t >> sr) | (d << sl));
+            t = d;
+        }
+
+        let remainder = rest.chunks_exact(4).remainder();
+
+        if remainder.len() >= align {
+            let d = read_tail(&remainder[..align]);
+            h = mmix(h, (t >> sr) | (d << sl));
+
+            let remainder = &remainder[align..];
+            if !remainder.is_empty() {
+                h ^= read_tail(remainder);
+                h = h.wrapping_mul(M);
+            }
+        } else {
+            let d = read_tail(remainder);
+            h ^= (t >> sr) | (d << sl);
+            h = h.wrapping_mul(M);
+        }
+    } else {
+        for chunk in bytes.chunks_exact(4) {
+            h = mmix(h, read_aligned_le32(chunk));
+        }
+
+        let remainder = bytes.chunks_exact(4).remainder();
+        if !remainder.is_empty() {
+            h ^= read_tail(remainder);
+            h = h.wrapping_mul(M);
+        }
+    }
+
+    fmix(h)
+}
+
+/// Computes the 32-bit **MurmurHashAligned2** hash of the given byte slice.
+///
+/// # Parameters
+/// - `bytes`: The input data to hash.
+///
+/// # Returns
+/// A 32-bit hash value derived from the input.
+pub fn murmurhash_aligned2(bytes: &[u8]) -> u32 {
+    murmurhash_aligned2_with_seed(bytes, 0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        murmurhash_aligned2, murmurhash_aligned2_with_seed, murmurhash_neutral2,
+        murmurhash_neutral2_with_seed, murmurhash2_32, murmurhash2_32_blocks,
+        murmurhash2_32_with_seed, murmurhash2a_32, murmurhash2a_32_with_seed,
+    };
+
+    #[test]
+    fn test_empty_string() {
+        assert_eq!(murmurhash2_32("".as_bytes()), 0);
+        assert_eq!(
+            murmurhash2_32_with_seed("".as_bytes(), 0x9747b28c),
+            275646681
+        );
+        assert_eq!(murmurhash2a_32("".as_bytes()), 0);
+        assert_eq!(
+            murmurhash2a_32_with_seed("".as_bytes(), 0x9747b28c),
+            3816574809
+        );
+    }
+
+    #[test]
+    fn test_tail_lengths() {
+        assert_eq!(murmurhash2_32("1".as_bytes()), 1228156847);
+        assert_eq!(murmurhash2_32("12".as_bytes()), 3282964525);
+        assert_eq!(murmurhash2_32("123".as_bytes()), 2461496879);
+        assert_eq!(murmurhash2_32("1234".as_bytes()), 25465593);
+
+        assert_eq!(murmurhash2a_32("1".as_bytes()), 1466639702);
+        assert_eq!(murmurhash2a_32("12".as_bytes()), 2790954381);
+        assert_eq!(murmurhash2a_32("123".as_bytes()), 1074609160);
+        assert_eq!(murmurhash2a_32("1234".as_bytes()), 2421780708);
+    }
+
+    #[test]
+    fn test_large_data() {
+        assert_eq!(
+            murmurhash2_32("The quick brown fox jumps over the lazy dog".as_bytes()),
+            556214736
+        );
+        assert_eq!(
+            murmurhash2_32_with_seed(
+                "Rust high performace utilities for YUV format handling and conversion.".as_bytes(),
+                0x9747b28c
+            ),
+            1669195974
+        );
+        assert_eq!(
+            murmurhash2a_32("The quick brown fox jumps over the lazy dog".as_bytes()),
+            1407301093
+        );
+        assert_eq!(
+            murmurhash2a_32_with_seed("432432 gfdsafgsd 32432 fds".as_bytes(), 0x9747b28c),
+            2442028232
+        );
+        assert_eq!(murmurhash2_32("MurmurHash2 (32-bit, x86)—The original version; contains a flaw that weakens collision in some cases.[9]
+MurmurHash2A (32-bit, x86)—A fixed variant using Merkle–Damgård construction. Slightly slower.
+CMurmurHash2A (32-bit, x86)—MurmurHash2A, but works incrementally.
+MurmurHashNeutral2 (32-bit, x86)—Slower, but endian- and alignment-neutral.
+MurmurHashAligned2 (32-bit, x86)—Slower, but does aligned reads (safer on some platforms).
+MurmurHash64A (64-bit, x64)—The original 64-bit version. Optimized for 64-bit arithmetic.
+MurmurHash64B (64-bit, x86)—A 64-bit version optimized for 32-bit platforms. It is not a true 64-bit hash due to insufficient mixing of the stripes.[10]
+".as_bytes()), 3143253469);
+        assert_eq!(murmurhash2a_32("MurmurHash2 (32-bit, x86)—The original version; contains a flaw that weakens collision in some cases.[9]
+MurmurHash2A (32-bit, x86)—A fixed variant using Merkle–Damgård construction. Slightly slower.
+CMurmurHash2A (32-bit, x86)—MurmurHash2A, but works incrementally.
+MurmurHashNeutral2 (32-bit, x86)—Slower, but endian- and alignment-neutral.
+MurmurHashAligned2 (32-bit, x86)—Slower, but does aligned reads (safer on some platforms).
+MurmurHash64A (64-bit, x64)—The original 64-bit version. Optimized for 64-bit arithmetic.
+MurmurHash64B (64-bit, x86)—A 64-bit version optimized for 32-bit platforms. It is not a true 64-bit hash due to insufficient mixing of the stripes.[10]
+".as_bytes()), 455341203);
+    }
+
+    #[test]
+    fn test_dispatch_matches_generic() {
+        let data: Vec<u8> = (0..300u32).map(|x| (x * 29 + 11) as u8).collect();
+        for len in 0..data.len() {
+            assert_eq!(
+                super::murmurhash2_32_dispatch(&data[..len], len as u32),
+                murmurhash2_32_blocks(&data[..len], len as u32)
+            );
+        }
+    }
+
+    #[test]
+    fn test_neutral2_vectors() {
+        assert_eq!(murmurhash_neutral2("".as_bytes()), 0);
+        assert_eq!(murmurhash_neutral2("1".as_bytes()), 1228156847);
+        assert_eq!(murmurhash_neutral2("12".as_bytes()), 3282964525);
+        assert_eq!(murmurhash_neutral2("123".as_bytes()), 2461496879);
+        assert_eq!(murmurhash_neutral2("1234".as_bytes()), 25465593);
+        assert_eq!(
+            murmurhash_neutral2_with_seed(
+                "The quick brown fox jumps over the lazy dog".as_bytes(),
+                0x9747b28c
+            ),
+            495243318
+        );
+        assert_eq!(
+            murmurhash_neutral2_with_seed("432432 gfdsafgsd 32432 fds".as_bytes(), 0x9747b28c),
+            3301404050
+        );
+    }
+
+    #[test]
+    fn test_aligned2_vectors() {
+        assert_eq!(murmurhash_aligned2("".as_bytes()), 0);
+        assert_eq!(murmurhash_aligned2("1".as_bytes()), 1228156847);
+        assert_eq!(murmurhash_aligned2("1234".as_bytes()), 25465593);
+        assert_eq!(
+            murmurhash_aligned2_with_seed("1".as_bytes(), 0x9747b28c),
+            2301521807
+        );
+        assert_eq!(
+            murmurhash_aligned2_with_seed(
+                "Rust high performace utilities for YUV format handling and conversion.".as_bytes(),
+                0x9747b28c
+            ),
+            1669195974
+        );
+    }
+
+    #[test]
+    fn test_aligned2_every_offset() {
+        let mut storage = vec![0u32; 80];
+        // SAFETY: the u32 storage only serves as a 4-byte aligned byte buffer.
+        let aligned_bytes = unsafe {
+            std::slice::from_raw_parts_mut(storage.as_mut_ptr().cast::<u8>(), storage.len() * 4)
+        };
+        for (i, byte) in aligned_bytes.iter_mut().enumerate() {
+            *byte = (i * 37 + 11) as u8;
+        }
+        for offset in 0..4 {
+            for len in 0..(aligned_bytes.len() - offset) {
+                let input = &aligned_bytes[offset..offset + len];
+                let expected = murmurhash2_32_with_seed(input, 0x9747b28c);
+                assert_eq!(murmurhash_aligned2_with_seed(input, 0x9747b28c), expected);
+                assert_eq!(murmurhash_neutral2_with_seed(input, 0x9747b28c), expected);
+            }
+        }
+    }
+}
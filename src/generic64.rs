@@ -26,20 +26,22 @@
  * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
+use crate::buffer::InlineBuffer;
+use crate::generic32::read_le32;
+use std::fmt;
+use std::hash::Hasher;
 
 #[inline(always)]
 pub(crate) fn read_le64(bytes: &[u8]) -> u64 {
-    u64::from_ne_bytes([
+    u64::from_le_bytes([
         bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
     ])
 }
 
-#[inline]
-fn murmurhash2_64_with_seed_impl(bytes: &[u8], seed: u64) -> u64 {
-    const M: u64 = 0xc6a4a7935bd1e995;
-
-    let mut h = seed ^ ((bytes.len() as u64).wrapping_mul(M));
+pub(crate) const M: u64 = 0xc6a4a7935bd1e995;
 
+#[inline]
+pub(crate) fn murmurhash2_64_blocks(bytes: &[u8], mut h: u64) -> u64 {
     for chunk in bytes.chunks_exact(32) {
         let mut k0 = read_le64(chunk);
         let mut k1 = read_le64(&chunk[8..16]);
@@ -78,19 +80,38 @@ fn murmurhash2_64_with_seed_impl(bytes: &[u8], seed: u64) -> u64 {
     let rem = bytes.chunks_exact(32).remainder();
 
     for chunk in rem.chunks_exact(8) {
-        let mut k = read_le64(chunk);
+        h = murmurhash2_64_round(h, read_le64(chunk));
+    }
 
-        k = k.wrapping_mul(M);
-        k ^= k >> 47;
-        k = k.wrapping_mul(M);
+    h
+}
 
-        h ^= k;
-        h = h.wrapping_mul(M);
-    }
+/// Mixes a single 8-byte block `k` into `h`.
+#[inline(always)]
+pub(crate) const fn murmurhash2_64_round(h: u64, k: u64) -> u64 {
+    let mut k = k;
+    k = k.wrapping_mul(M);
+    k ^= k >> 47;
+    k = k.wrapping_mul(M);
 
-    let remainder = rem.chunks_exact(8).remainder();
+    (h ^ k).wrapping_mul(M)
+}
+
+fn murmurhash2_64_dispatch(bytes: &[u8], h: u64) -> u64 {
+    #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+    {
+        use crate::avx512::avx512_murmurhash2_64_blocks;
+        use crate::backend::{Backend, active_backend};
+        if active_backend().allows(Backend::Avx512) {
+            return unsafe { avx512_murmurhash2_64_blocks(bytes, h) };
+        }
+    }
+    murmurhash2_64_blocks(bytes, h)
+}
 
-    let quot = bytes.len() & 7;
+#[inline]
+const fn murmurhash2_64_finalize(remainder: &[u8], mut h: u64) -> u64 {
+    let quot = remainder.len();
     if quot > 0 {
         if quot == 7 {
             h ^= (remainder[6] as u64).wrapping_shl(48);
@@ -123,6 +144,12 @@ fn murmurhash2_64_with_seed_impl(bytes: &[u8], seed: u64) -> u64 {
     h
 }
 
+#[inline]
+fn murmurhash2_64_with_seed_impl(bytes: &[u8], seed: u64) -> u64 {
+    let h = murmurhash2_64_dispatch(bytes, seed ^ (bytes.len() as u64).wrapping_mul(M));
+    murmurhash2_64_finalize(bytes.chunks_exact(8).remainder(), h)
+}
+
 /// Computes a 64-bit Murmur2 hash of the given byte slice with a seed.
 ///
 /// Murmur2 is a non-cryptographic hash function known for good
@@ -140,6 +167,29 @@ pub fn murmurhash2_64_with_seed(bytes: &[u8], seed: u64) -> u64 {
     murmurhash2_64_with_seed_impl(bytes, seed)
 }
 
+/// `const` version of [`murmurhash2_64_with_seed`].
+pub const fn murmurhash2_64_const(bytes: &[u8], seed: u64) -> u64 {
+    let blocks = bytes.len() / 8;
+    let mut h = seed ^ (bytes.len() as u64).wrapping_mul(M);
+    let mut i = 0;
+    while i < blocks {
+        let j = i * 8;
+        let k = u64::from_le_bytes([
+            bytes[j],
+            bytes[j + 1],
+            bytes[j + 2],
+            bytes[j + 3],
+            bytes[j + 4],
+            bytes[j + 5],
+            bytes[j + 6],
+            bytes[j + 7],
+        ]);
+        h = murmurhash2_64_round(h, k);
+        i += 1;
+    }
+    murmurhash2_64_finalize(bytes.split_at(blocks * 8).1, h)
+}
+
 /// Computes the 64-bit MurmurHash2 of a byte slice.
 ///
 /// This is a fast, non-cryptographic hash function suitable for hash tables
@@ -157,9 +207,276 @@ pub fn murmurhash2_64(bytes: &[u8]) -> u64 {
     murmurhash2_64_with_seed_impl(bytes, 0)
 }
 
+/// Computes the 64-bit **MurmurHash64B** of a byte slice with a seed.
+///
+/// MurmurHash64B produces a 64-bit value from two interleaved 32-bit streams,
+/// so it only needs 32-bit multiplies and is much cheaper than
+/// [`murmurhash2_64_with_seed`] (MurmurHash64A) on 32-bit targets. The two
+/// variants give different outputs for the same input.
+///
+/// # Parameters
+///
+/// - `bytes`: The input byte slice to hash.
+/// - `seed`: A 64-bit seed; its low half seeds the first stream and its high
+///   half the second one.
+///
+/// # Returns
+///
+/// A `u64` whose high 32 bits come from the first stream and low 32 bits
+/// from the second, matching the reference implementation.
+///
+/// # Notes
+/// - This is not a true 64-bit hash: the two streams are not fully mixed.
+pub fn murmurhash2_64b_with_seed(bytes: &[u8], seed: u64) -> u64 {
+    const M: u32 = 0x5bd1_e995;
+
+    let mut h1 = (seed as u32) ^ (bytes.len() as u32);
+    let mut h2 = (seed >> 32) as u32;
+
+    for chunk in bytes.chunks_exact(8) {
+        let mut k1 = read_le32(chunk);
+        k1 = k1.wrapping_mul(M);
+        k1 ^= k1 >> 24;
+        k1 = k1.wrapping_mul(M);
+        h1 = h1.wrapping_mul(M);
+        h1 ^= k1;
+
+        let mut k2 = read_le32(&chunk[4..8]);
+        k2 = k2.wrapping_mul(M);
+        k2 ^= k2 >> 24;
+        k2 = k2.wrapping_mul(M);
+        h2 = h2.wrapping_mul(M);
+        h2 ^= k2;
+    }
+
+    let mut remainder = bytes.chunks_exact(8).remainder();
+
+    if remainder.len() >= 4 {
+        let mut k1 = read_le32(remainder);
+        k1 = k1.wrapping_mul(M);
+        k1 ^= k1 >> 24;
+        k1 = k1.wrapping_mul(M);
+        h1 = h1.wrapping_mul(M);
+        h1 ^= k1;
+        remainder = &remainder[4..];
+    }
+
+    let quot = remainder.len();
+    if quot > 0 {
+        if quot == 3 {
+            h2 ^= (remainder[2] as u32) << 16;
+        }
+        if quot >= 2 {
+            h2 ^= (remainder[1] as u32) << 8;
+        }
+        h2 ^= remainder[0] as u32;
+        h2 = h2.wrapping_mul(M);
+    }
+
+    h1 ^= h2 >> 18;
+    h1 = h1.wrapping_mul(M);
+    h2 ^= h1 >> 22;
+    h2 = h2.wrapping_mul(M);
+    h1 ^= h2 >> 17;
+    h1 = h1.wrapping_mul(M);
+    h2 ^= h1 >> 19;
+    h2 = h2.wrapping_mul(M);
+
+    ((h1 as u64) << 32) | (h2 as u64)
+}
+
+/// Computes the 64-bit **MurmurHash64B** of a byte slice using a seed of 0.
+///
+/// # Parameters
+///
+/// - `bytes`: The input data to hash as a byte slice (`&[u8]`).
+///
+/// # Returns
+///
+/// A 64-bit hash (`u64`) of the input data.
+pub fn murmurhash2_64b(bytes: &[u8]) -> u64 {
+    murmurhash2_64b_with_seed(bytes, 0)
+}
+
+/// Error returned by [`MurmurHash64AStream`] when the number of bytes fed
+/// does not add up to the length declared in [`MurmurHash64AStream::with_len`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LengthMismatch {
+    /// The total length declared up front.
+    pub expected: u64,
+    /// The number of bytes that was, or would have been, fed.
+    pub actual: u64,
+}
+
+impl fmt::Display for LengthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "MurmurHash64A stream declared {} bytes, but {} were fed",
+            self.expected, self.actual
+        )
+    }
+}
+
+impl std::error::Error for LengthMismatch {}
+
+/// A streaming implementation of **MurmurHash64A** for inputs of known length.
+///
+/// MurmurHash64A mixes the total input length into its initial state, so the
+/// length has to be declared before any data is fed. Chunks passed to
+/// [`MurmurHash64AStream::update`] may have arbitrary sizes; complete 8-byte
+/// blocks are mixed immediately and up to 7 bytes are carried over.
+///
+/// Once exactly the declared number of bytes has been fed,
+/// [`MurmurHash64AStream::finalize`] returns the same value as
+/// [`murmurhash2_64_with_seed`] over the concatenated input.
+#[derive(Clone, Debug)]
+pub struct MurmurHash64AStream {
+    h: u64,
+    buffer: [u8; 8],
+    buffer_len: usize,
+    expected: u64,
+    fed: u64,
+}
+
+impl MurmurHash64AStream {
+    /// Creates a new stream that will hash exactly `total_len` bytes.
+    ///
+    /// # Parameters
+    /// - `seed`: A 64-bit seed value to influence the hash output.
+    /// - `total_len`: The number of bytes that will be fed in total.
+    pub fn with_len(seed: u64, total_len: u64) -> Self {
+        Self {
+            h: seed ^ total_len.wrapping_mul(M),
+            buffer: [0; 8],
+            buffer_len: 0,
+            expected: total_len,
+            fed: 0,
+        }
+    }
+
+    /// Feeds the next chunk of input into the hash state.
+    ///
+    /// # Errors
+    /// Returns [`LengthMismatch`] if the chunk would exceed the declared
+    /// length; in that case the state is left unchanged.
+    pub fn update(&mut self, bytes: &[u8]) -> Result<(), LengthMismatch> {
+        let actual = self.fed.saturating_add(bytes.len() as u64);
+        if actual > self.expected {
+            return Err(LengthMismatch {
+                expected: self.expected,
+                actual,
+            });
+        }
+        self.fed = actual;
+
+        let mut bytes = bytes;
+
+        if self.buffer_len != 0 {
+            let take = (8 - self.buffer_len).min(bytes.len());
+            self.buffer[self.buffer_len..self.buffer_len + take].copy_from_slice(&bytes[..take]);
+            self.buffer_len += take;
+            bytes = &bytes[take..];
+            if self.buffer_len < 8 {
+                return Ok(());
+            }
+            self.h = murmurhash2_64_blocks(&self.buffer, self.h);
+            self.buffer_len = 0;
+        }
+
+        let full = bytes.len() & !7;
+        self.h = murmurhash2_64_dispatch(&bytes[..full], self.h);
+
+        let rem = &bytes[full..];
+        self.buffer[..rem.len()].copy_from_slice(rem);
+        self.buffer_len = rem.len();
+        Ok(())
+    }
+
+    /// Returns the number of bytes that still have to be fed.
+    pub fn remaining(&self) -> u64 {
+        self.expected - self.fed
+    }
+
+    /// Returns the 64-bit hash of the fed input.
+    ///
+    /// # Errors
+    /// Returns [`LengthMismatch`] if fewer bytes than declared were fed.
+    pub fn finalize(&self) -> Result<u64, LengthMismatch> {
+        if self.fed != self.expected {
+            return Err(LengthMismatch {
+                expected: self.expected,
+                actual: self.fed,
+            });
+        }
+        Ok(murmurhash2_64_finalize(
+            &self.buffer[..self.buffer_len],
+            self.h,
+        ))
+    }
+}
+
+/// A [`Hasher`] computing **MurmurHash64A** over all written bytes.
+///
+/// MurmurHash64A mixes the total input length into its initial state, so
+/// nothing can be mixed before [`Hasher::finish`]; written bytes are
+/// collected instead. Keys of up to 64 bytes are kept inline, but **longer
+/// input is copied to a heap allocation** that grows with it, so e.g. a
+/// map keyed by long `String`s allocates on every lookup. The result equals
+/// [`murmurhash2_64_with_seed`] over the concatenated input.
+///
+/// For inputs of known length that should not be buffered use
+/// [`MurmurHash64AStream`]; for long map keys prefer a hasher that streams,
+/// such as [`Murmur3x64_128`](crate::Murmur3x64_128).
+#[derive(Clone, Debug)]
+pub struct Murmur64AHasher {
+    seed: u64,
+    buffer: InlineBuffer,
+}
+
+impl Murmur64AHasher {
+    /// Creates a new [`Murmur64AHasher`] with a default seed value of `0`.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new [`Murmur64AHasher`] initialized with the specified seed.
+    ///
+    /// # Parameters
+    /// - `seed`: A 64-bit seed value to influence the hash output.
+    pub fn new_with_seed(seed: u64) -> Self {
+        Self {
+            seed,
+            buffer: InlineBuffer::new(),
+        }
+    }
+}
+
+impl Default for Murmur64AHasher {
+    fn default() -> Self {
+        Self::new_with_seed(0)
+    }
+}
+
+impl Hasher for Murmur64AHasher {
+    fn finish(&self) -> u64 {
+        murmurhash2_64_with_seed(self.buffer.as_slice(), self.seed)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.buffer.extend(bytes);
+    }
+
+    le_integer_writes!();
+}
+
 #[cfg(test)]
 mod test {
-    use super::murmurhash2_64_with_seed;
+    use super::{
+        LengthMismatch, Murmur64AHasher, MurmurHash64AStream, murmurhash2_64_const,
+        murmurhash2_64_with_seed, murmurhash2_64b, murmurhash2_64b_with_seed,
+    };
+    use std::hash::Hasher;
 
     #[test]
     fn test_empty_string() {
@@ -186,6 +503,26 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_const_matches_runtime() {
+        const EVALUATED: u64 = murmurhash2_64_const(b"The quick brown fox", 0x9747b28c);
+        assert_eq!(
+            EVALUATED,
+            murmurhash2_64_with_seed(b"The quick brown fox", 0x9747b28c)
+        );
+
+        let data: Vec<u8> = (0..300u32).map(|x| (x * 7 + 3) as u8).collect();
+        for seed in [0, 1, 0x9747b28c, u64::MAX] {
+            for len in 0..=data.len() {
+                assert_eq!(
+                    murmurhash2_64_const(&data[..len], seed),
+                    murmurhash2_64_with_seed(&data[..len], seed),
+                    "length {len}, seed {seed}"
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_large_data() {
         assert_eq!(
@@ -201,4 +538,89 @@ mod test {
         );
         assert_eq!(murmurhash2_64_with_seed("MurmurHash2 (32-bit, x86)—The original version; contains a flaw that weakens collision in some cases.[9]MurmurHash2A (32-bit, x86)—A fixed variant using Merkle–Damgård construction. Slightly slower.".as_bytes(), 0), 12740723412636160583);
     }
+
+    #[test]
+    fn test_stream_splits() {
+        let data: Vec<u8> = (0..150u32).map(|x| (x * 31 + 1) as u8).collect();
+        for len in [0usize, 1, 7, 8, 9, 31, 32, 33, 64, 150] {
+            let expected = murmurhash2_64_with_seed(&data[..len], 0xdead_beef);
+            for split in 1..=9 {
+                let mut stream = MurmurHash64AStream::with_len(0xdead_beef, len as u64);
+                for chunk in data[..len].chunks(split) {
+                    stream.update(chunk).unwrap();
+                }
+                assert_eq!(stream.remaining(), 0);
+                assert_eq!(stream.finalize(), Ok(expected));
+            }
+        }
+    }
+
+    #[test]
+    fn test_hasher_splits() {
+        let data: Vec<u8> = (0..150u32).map(|x| (x * 31 + 1) as u8).collect();
+        for len in [0usize, 1, 7, 8, 63, 64, 65, 150] {
+            let expected = murmurhash2_64_with_seed(&data[..len], 0xdead_beef);
+            for split in 1..=9 {
+                let mut hasher = Murmur64AHasher::new_with_seed(0xdead_beef);
+                for chunk in data[..len].chunks(split) {
+                    hasher.write(chunk);
+                }
+                assert_eq!(hasher.finish(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_stream_length_mismatch() {
+        let mut stream = MurmurHash64AStream::with_len(0, 4);
+        stream.update(b"abc").unwrap();
+        assert_eq!(
+            stream.finalize(),
+            Err(LengthMismatch {
+                expected: 4,
+                actual: 3
+            })
+        );
+        assert_eq!(
+            stream.update(b"de"),
+            Err(LengthMismatch {
+                expected: 4,
+                actual: 5
+            })
+        );
+        stream.update(b"d").unwrap();
+        assert_eq!(stream.finalize(), Ok(murmurhash2_64_with_seed(b"abcd", 0)));
+    }
+
+    #[test]
+    fn test_64b_vectors() {
+        assert_eq!(murmurhash2_64b("".as_bytes()), 0);
+        assert_eq!(murmurhash2_64b("1".as_bytes()), 15408068432917495462);
+        assert_eq!(murmurhash2_64b("12".as_bytes()), 1946145033977080566);
+        assert_eq!(murmurhash2_64b("123".as_bytes()), 7981565540990688059);
+        assert_eq!(murmurhash2_64b("1234".as_bytes()), 15957037869888738558);
+        assert_eq!(
+            murmurhash2_64b("The quick brown fox jumps over the lazy dog".as_bytes()),
+            8470663747738974033
+        );
+        assert_eq!(
+            murmurhash2_64b_with_seed("".as_bytes(), 0x9747b28c),
+            377774710346637481
+        );
+        assert_eq!(
+            murmurhash2_64b_with_seed(
+                "Rust high performace utilities for YUV format handling and conversion.".as_bytes(),
+                0x9747b28c
+            ),
+            2263729065597448921
+        );
+        assert_eq!(
+            murmurhash2_64b_with_seed("432432 gfdsafgsd 32432 fds".as_bytes(), 0x0123456789abcdef),
+            921025399483434489
+        );
+        assert_eq!(
+            murmurhash2_64b_with_seed("1234".as_bytes(), 0x0123456789abcdef),
+            2348627472039746615
+        );
+    }
 }
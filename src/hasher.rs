@@ -27,6 +27,12 @@
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 
+use crate::backend::{Backend, STATIC_BACKEND, Unsupported, active_backend, backend_forced};
+use crate::generic::{murmurhash3_32_finalize, murmurhash3_32_round};
+use crate::generic32::{
+    mmix, murmurhash2_32_dispatch, murmurhash2a_32_finalize, read_le32, read_tail,
+};
+use crate::generic128::Murmur3x64_128;
 use std::hash::Hasher;
 
 /// A stateful implementation of the **MurmurHash3 (x86_32)** algorithm.
@@ -35,12 +41,24 @@ use std::hash::Hasher;
 /// for computing 32-bit MurmurHash3 hashes. It supports an optional seed
 /// value to randomize hash outputs.
 ///
+/// The hasher is fully incremental: every [`Hasher::write`] folds complete
+/// 4-byte blocks into the running state immediately and keeps at most 3
+/// carry bytes, so it never allocates regardless of how much data is fed.
+/// [`Murmur3Hasher::finish_32`] is bit-identical to [`murmurhash3_32_with_seed`]
+/// over the concatenation of all written bytes, while [`Hasher::finish`]
+/// returns the low 64 bits of **MurmurHash3 (x64_128)** over the same bytes
+/// for use in hash maps, at the cost of running both algorithms.
+///
 /// This hasher is **non-cryptographic** — it is optimized for speed and
 /// uniform distribution, making it ideal for hash tables, bloom filters,
 /// and general-purpose hashing, but **not for security-sensitive use**.
+#[derive(Clone, Debug)]
 pub struct Murmur3Hasher {
-    seed: u32,
-    bytes: Vec<u8>,
+    h1: u32,
+    wide: Murmur3x64_128,
+    tail: [u8; 4],
+    tail_len: usize,
+    len: usize,
 }
 
 /// Computes the 32-bit **MurmurHash3** hash of the given byte slice using a custom seed.
@@ -63,38 +81,21 @@ pub struct Murmur3Hasher {
 /// - Using different seeds for the same input will yield different hash values.
 /// - This function implements the **MurmurHash3 x86_32** variant.
 /// - It is **not suitable for cryptographic purposes**.
+#[inline]
 pub fn murmurhash3_32_with_seed(bytes: &[u8], seed: u32) -> u32 {
-    #[cfg(all(target_arch = "aarch64", feature = "neon"))]
-    {
-        use crate::neon::neon_murmurhash3_32;
-        neon_murmurhash3_32(bytes, seed)
+    // Short keys are a handful of scalar rounds, the SIMD kernels only pay
+    // off once there are several blocks to pre-mix.
+    if bytes.len() <= 16 {
+        return crate::generic::murmurhash3_32(bytes, seed);
     }
-    #[cfg(not(all(target_arch = "aarch64", feature = "neon")))]
-    {
-        use std::sync::OnceLock;
-        type HashFn = unsafe fn(&[u8], u32) -> u32;
-        static EXECUTOR: OnceLock<HashFn> = OnceLock::new();
-
-        let func = EXECUTOR.get_or_init(|| {
-            #[cfg(all(target_arch = "x86_64", feature = "avx"))]
-            {
-                if std::arch::is_x86_feature_detected!("avx2") {
-                    use crate::avx::avx_murmurhash3_32;
-                    return avx_murmurhash3_32;
-                }
-            }
-            #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "sse"))]
-            {
-                if std::arch::is_x86_feature_detected!("sse2") {
-                    use crate::sse::sse_murmurhash3_32;
-                    return sse_murmurhash3_32;
-                }
-            }
-
-            crate::generic::murmurhash3_32
-        });
-        unsafe { func(bytes, seed) }
+    // A backend enabled at compile time is a constant here, so its kernel
+    // is called directly without loading the active backend.
+    if let Some(backend) = STATIC_BACKEND {
+        if !backend_forced() {
+            return unsafe { murmurhash3_32_kernel(bytes, seed, backend) };
+        }
     }
+    unsafe { murmurhash3_32_kernel(bytes, seed, active_backend()) }
 }
 
 /// Computes the 32-bit **MurmurHash3** hash of the given byte slice.
@@ -107,57 +108,251 @@ pub fn murmurhash3_32_with_seed(bytes: &[u8], seed: u32) -> u32 {
 ///
 /// # Returns
 /// A 32-bit hash value derived from the input.
+#[inline]
 pub fn murmurhash3_32(bytes: &[u8]) -> u32 {
+    murmurhash3_32_with_seed(bytes, 0)
+}
+
+/// Computes the 32-bit **MurmurHash3** hash of `bytes` with the kernel of an
+/// explicitly chosen [`Backend`], regardless of [`active_backend`].
+///
+/// Unlike [`murmurhash3_32_with_seed`] this never takes the scalar short-input
+/// path, so every input length exercises the requested kernel. This is meant
+/// for testing and benchmarking individual kernels.
+///
+/// # Errors
+/// Returns [`Unsupported`] if `backend` is not compiled in or not supported
+/// by the running CPU.
+pub fn murmurhash3_32_with_backend(
+    bytes: &[u8],
+    seed: u32,
+    backend: Backend,
+) -> Result<u32, Unsupported> {
+    if !backend.is_supported() {
+        return Err(Unsupported { backend });
+    }
+    Ok(unsafe { murmurhash3_32_kernel(bytes, seed, backend) })
+}
+
+/// Hashes `bytes` with the MurmurHash3 x86_32 kernel of `backend`.
+///
+/// # Safety
+/// `backend` must be supported by the running CPU.
+#[inline(always)]
+pub(crate) unsafe fn murmurhash3_32_kernel(bytes: &[u8], seed: u32, backend: Backend) -> u32 {
+    debug_assert!(backend.is_supported());
     #[cfg(all(target_arch = "aarch64", feature = "neon"))]
     {
         use crate::neon::neon_murmurhash3_32;
-        neon_murmurhash3_32(bytes, 0)
+        if backend == Backend::Neon {
+            return neon_murmurhash3_32(bytes, seed);
+        }
     }
-    #[cfg(not(all(target_arch = "aarch64", feature = "neon")))]
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128", feature = "wasm"))]
     {
-        use std::sync::OnceLock;
-        type HashFn = unsafe fn(&[u8], u32) -> u32;
-        static EXECUTOR: OnceLock<HashFn> = OnceLock::new();
-
-        let func = EXECUTOR.get_or_init(|| {
-            #[cfg(all(target_arch = "x86_64", feature = "avx"))]
-            {
-                if std::arch::is_x86_feature_detected!("avx2") {
-                    use crate::avx::avx_murmurhash3_32;
-                    return avx_murmurhash3_32;
-                }
-            }
-            #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "sse"))]
-            {
-                if std::arch::is_x86_feature_detected!("sse2") {
-                    use crate::sse::sse_murmurhash3_32;
-                    return sse_murmurhash3_32;
-                }
-            }
+        use crate::wasm::wasm_murmurhash3_32;
+        if backend == Backend::WasmSimd128 {
+            return wasm_murmurhash3_32(bytes, seed);
+        }
+    }
+    #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+    {
+        use crate::avx512::avx512_murmurhash3_32;
+        if backend.allows(Backend::Avx512) {
+            return unsafe { avx512_murmurhash3_32(bytes, seed) };
+        }
+    }
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx"))]
+    {
+        use crate::avx::avx_murmurhash3_32;
+        if backend.allows(Backend::Avx2) {
+            return unsafe { avx_murmurhash3_32(bytes, seed) };
+        }
+    }
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "sse"))]
+    {
+        use crate::sse::{sse2_murmurhash3_32, sse41_murmurhash3_32};
+        if backend.allows(Backend::Sse41) {
+            return unsafe { sse41_murmurhash3_32(bytes, seed) };
+        }
+        if backend.allows(Backend::Sse2) {
+            return unsafe { sse2_murmurhash3_32(bytes, seed) };
+        }
+    }
+    crate::generic::murmurhash3_32(bytes, seed)
+}
 
-            crate::generic::murmurhash3_32
-        });
-        unsafe { func(bytes, 0) }
+/// Mixes all complete 4-byte blocks of `bytes` into `h1` using the kernel of
+/// the active backend.
+fn murmurhash3_32_blocks(bytes: &[u8], h1: u32) -> u32 {
+    if bytes.len() <= 16 {
+        return crate::generic::murmurhash3_32_blocks(bytes, h1);
     }
+    if let Some(backend) = STATIC_BACKEND {
+        if !backend_forced() {
+            return unsafe { murmurhash3_32_blocks_kernel(bytes, h1, backend) };
+        }
+    }
+    unsafe { murmurhash3_32_blocks_kernel(bytes, h1, active_backend()) }
+}
+
+/// Mixes all complete 4-byte blocks of `bytes` into `h1` with the block
+/// kernel of `backend`.
+///
+/// # Safety
+/// `backend` must be supported by the running CPU.
+#[inline(always)]
+unsafe fn murmurhash3_32_blocks_kernel(bytes: &[u8], h1: u32, backend: Backend) -> u32 {
+    debug_assert!(backend.is_supported());
+    #[cfg(all(target_arch = "aarch64", feature = "neon"))]
+    {
+        use crate::neon::neon_murmurhash3_32_blocks;
+        if backend == Backend::Neon {
+            return neon_murmurhash3_32_blocks(bytes, h1);
+        }
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128", feature = "wasm"))]
+    {
+        use crate::wasm::wasm_murmurhash3_32_blocks;
+        if backend == Backend::WasmSimd128 {
+            return wasm_murmurhash3_32_blocks(bytes, h1);
+        }
+    }
+    #[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+    {
+        use crate::avx512::avx512_murmurhash3_32_blocks;
+        if backend.allows(Backend::Avx512) {
+            return unsafe { avx512_murmurhash3_32_blocks(bytes, h1) };
+        }
+    }
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx"))]
+    {
+        use crate::avx::avx_murmurhash3_32_blocks;
+        if backend.allows(Backend::Avx2) {
+            return unsafe { avx_murmurhash3_32_blocks(bytes, h1) };
+        }
+    }
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "sse"))]
+    {
+        use crate::sse::{sse2_murmurhash3_32_blocks, sse41_murmurhash3_32_blocks};
+        if backend.allows(Backend::Sse41) {
+            return unsafe { sse41_murmurhash3_32_blocks(bytes, h1) };
+        }
+        if backend.allows(Backend::Sse2) {
+            return unsafe { sse2_murmurhash3_32_blocks(bytes, h1) };
+        }
+    }
+    crate::generic::murmurhash3_32_blocks(bytes, h1)
 }
 
 impl Hasher for Murmur3Hasher {
+    /// Returns the low 64 bits of [`murmurhash3_128_with_seed`](crate::murmurhash3_128_with_seed)
+    /// over all written bytes.
+    ///
+    /// `HashMap` takes its control tag from the top bits of the hash and the
+    /// bucket index from the low bits, so both halves must be well mixed.
+    /// The 32-bit value is available as [`finish_32`](Murmur3Hasher::finish_32).
     fn finish(&self) -> u64 {
-        murmurhash3_32_with_seed(&self.bytes, self.seed) as u64
+        self.wide.finish()
     }
 
     fn write(&mut self, bytes: &[u8]) {
-        let mut copy = bytes.to_vec();
-        self.bytes.append(&mut copy);
+        self.wide.update(bytes);
+        self.len = self.len.wrapping_add(bytes.len());
+        let mut bytes = bytes;
+
+        if self.tail_len != 0 {
+            let take = (4 - self.tail_len).min(bytes.len());
+            self.tail[self.tail_len..self.tail_len + take].copy_from_slice(&bytes[..take]);
+            self.tail_len += take;
+            bytes = &bytes[take..];
+            if self.tail_len < 4 {
+                return;
+            }
+            self.h1 = murmurhash3_32_round(self.h1, u32::from_le_bytes(self.tail));
+            self.tail_len = 0;
+        }
+
+        let full = bytes.len() & !3;
+        if full != 0 {
+            self.h1 = murmurhash3_32_blocks(&bytes[..full], self.h1);
+        }
+
+        let rem = &bytes[full..];
+        self.tail[..rem.len()].copy_from_slice(rem);
+        self.tail_len = rem.len();
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.push(i as u32, 1);
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.push(i as u32, 2);
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.push(i, 4);
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.push(i as u32, 4);
+        self.push((i >> 32) as u32, 4);
+    }
+
+    #[inline]
+    fn write_u128(&mut self, i: u128) {
+        self.write_u64(i as u64);
+        self.write_u64((i >> 64) as u64);
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        match usize::BITS {
+            64 => self.write_u64(i as u64),
+            32 => self.write_u32(i as u32),
+            _ => self.write(&i.to_le_bytes()),
+        }
+    }
+
+    #[inline]
+    fn write_i8(&mut self, i: i8) {
+        self.write_u8(i as u8);
+    }
+
+    #[inline]
+    fn write_i16(&mut self, i: i16) {
+        self.write_u16(i as u16);
+    }
+
+    #[inline]
+    fn write_i32(&mut self, i: i32) {
+        self.write_u32(i as u32);
+    }
+
+    #[inline]
+    fn write_i64(&mut self, i: i64) {
+        self.write_u64(i as u64);
+    }
+
+    #[inline]
+    fn write_i128(&mut self, i: i128) {
+        self.write_u128(i as u128);
+    }
+
+    #[inline]
+    fn write_isize(&mut self, i: isize) {
+        self.write_usize(i as usize);
     }
 }
 
 impl Default for Murmur3Hasher {
     fn default() -> Self {
-        Self {
-            seed: 0,
-            bytes: vec![0; 0],
-        }
+        Self::new_with_seed(0)
     }
 }
 
@@ -176,15 +371,143 @@ impl Murmur3Hasher {
     /// - `seed`: A 32-bit integer used to initialize the hash state.
     pub fn new_with_seed(seed: u32) -> Self {
         Self {
-            seed,
-            bytes: vec![0; 0],
+            h1: seed,
+            wide: Murmur3x64_128::new_with_seed(seed),
+            tail: [0; 4],
+            tail_len: 0,
+            len: 0,
         }
     }
+
+    /// Appends the `n` (at most 4) low bytes of `k` in little-endian order,
+    /// mixing a block straight into `h1` once 4 bytes are pending.
+    ///
+    /// This is what the integer `write_*` methods use, so hashing a derived
+    /// `Hash` key is a few scalar rounds without touching the slice path.
+    #[inline(always)]
+    fn push(&mut self, k: u32, n: usize) {
+        self.wide.update(&k.to_le_bytes()[..n]);
+        let shift = 8 * self.tail_len;
+        let pending = u32::from_le_bytes(self.tail) as u64 & ((1u64 << shift) - 1);
+        let mut acc = pending | ((k as u64) << shift);
+        self.tail_len += n;
+        self.len = self.len.wrapping_add(n);
+        if self.tail_len >= 4 {
+            self.h1 = murmurhash3_32_round(self.h1, acc as u32);
+            acc >>= 32;
+            self.tail_len -= 4;
+        }
+        self.tail = (acc as u32).to_le_bytes();
+    }
+
+    /// Returns the 32-bit **MurmurHash3 (x86_32)** of all bytes written so far.
+    ///
+    /// This equals [`murmurhash3_32_with_seed`] over the concatenated input.
+    /// The state is not consumed, so more data may be written afterwards.
+    pub fn finish_32(&self) -> u32 {
+        murmurhash3_32_finalize(&self.tail[..self.tail_len], self.h1, self.len)
+    }
+}
+
+/// An incremental implementation of **MurmurHash2A**, equivalent to the
+/// reference `CMurmurHash2A` class.
+///
+/// [`Murmur2AHasher::new_with_seed`] corresponds to `Begin(seed)`,
+/// [`Murmur2AHasher::add`] (or [`Hasher::write`]) to `Add(data, len)` and
+/// [`Murmur2AHasher::end`] to `End()`. Data is mixed as it arrives with at
+/// most 3 carry bytes kept between calls, so fields can be hashed one by one
+/// without concatenating them, and the result equals
+/// [`murmurhash2a_32_with_seed`](crate::murmurhash2a_32_with_seed) over the
+/// concatenated input.
+///
+/// This hasher is **non-cryptographic** and **not suitable for security-sensitive use**.
+#[derive(Clone, Debug)]
+pub struct Murmur2AHasher {
+    h: u32,
+    tail: [u8; 4],
+    tail_len: usize,
+    size: usize,
+}
+
+impl Murmur2AHasher {
+    /// Creates a new [`Murmur2AHasher`] with a default seed value of `0`.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new [`Murmur2AHasher`] initialized with the specified seed.
+    ///
+    /// # Parameters
+    /// - `seed`: A 32-bit integer used to initialize the hash state.
+    pub fn new_with_seed(seed: u32) -> Self {
+        Self {
+            h: seed,
+            tail: [0; 4],
+            tail_len: 0,
+            size: 0,
+        }
+    }
+
+    /// Feeds the next chunk of input into the hash state.
+    pub fn add(&mut self, bytes: &[u8]) {
+        self.size = self.size.wrapping_add(bytes.len());
+        let mut bytes = bytes;
+
+        if self.tail_len != 0 {
+            let take = (4 - self.tail_len).min(bytes.len());
+            self.tail[self.tail_len..self.tail_len + take].copy_from_slice(&bytes[..take]);
+            self.tail_len += take;
+            bytes = &bytes[take..];
+            if self.tail_len < 4 {
+                return;
+            }
+            self.h = mmix(self.h, read_le32(&self.tail));
+            self.tail_len = 0;
+        }
+
+        let full = bytes.len() & !3;
+        if full != 0 {
+            self.h = murmurhash2_32_dispatch(&bytes[..full], self.h);
+        }
+
+        let rem = &bytes[full..];
+        self.tail[..rem.len()].copy_from_slice(rem);
+        self.tail_len = rem.len();
+    }
+
+    /// Returns the 32-bit MurmurHash2A of all bytes added so far.
+    ///
+    /// The state is not consumed, so more data may be added afterwards.
+    pub fn end(&self) -> u32 {
+        murmurhash2a_32_finalize(read_tail(&self.tail[..self.tail_len]), self.h, self.size)
+    }
+}
+
+impl Default for Murmur2AHasher {
+    fn default() -> Self {
+        Self::new_with_seed(0)
+    }
+}
+
+impl Hasher for Murmur2AHasher {
+    fn finish(&self) -> u64 {
+        self.end() as u64
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.add(bytes);
+    }
+
+    le_integer_writes!();
 }
 
 #[cfg(test)]
 mod test {
-    use super::Murmur3Hasher;
+    use super::{
+        Backend, Murmur2AHasher, Murmur3Hasher, Unsupported, murmurhash3_32_with_backend,
+        murmurhash3_32_with_seed,
+    };
+    use crate::{murmurhash2a_32_with_seed, murmurhash3_128_with_seed};
     use std::hash::Hasher;
 
     #[test]
@@ -193,7 +516,150 @@ mod test {
         hasher.write_i16(0x1234);
         hasher.write_i16(0x1234);
         hasher.write_i16(0x1234);
-        let op = hasher.finish();
+        let op = hasher.finish_32() as u64;
         assert_eq!(op, 0x3c09ef02u64);
+        assert_eq!(
+            hasher.finish(),
+            murmurhash3_128_with_seed(&[0x34, 0x12, 0x34, 0x12, 0x34, 0x12], 0) as u64
+        );
+    }
+
+    #[test]
+    fn finish_separates_32_bit_collisions() {
+        let key = |i: u64| i.wrapping_mul(0x9e37_79b9_7f4a_7c15).to_le_bytes();
+
+        let hash = |i: u64| {
+            let mut hasher = Murmur3Hasher::new();
+            hasher.write(&key(i));
+            (hasher.finish_32(), hasher.finish())
+        };
+        let (a, b) = (hash(7361), hash(138964));
+        assert_eq!(a.0, b.0);
+        assert_ne!(a.1, b.1);
+    }
+
+    #[test]
+    fn integer_writes_match_bytes() {
+        for lead in 0..4usize {
+            let mut fast = Murmur3Hasher::new_with_seed(0x9747b28c);
+            let mut slow = Murmur3Hasher::new_with_seed(0x9747b28c);
+            fast.write(&[0xa5; 3][..lead]);
+            slow.write(&[0xa5; 3][..lead]);
+
+            fast.write_u8(0x12);
+            fast.write_u16(0x3456);
+            fast.write_u32(0x789a_bcde);
+            fast.write_u64(0x0123_4567_89ab_cdef);
+            fast.write_u128(0xfedc_ba98_7654_3210_0f1e_2d3c_4b5a_6978);
+            fast.write_usize(0x1357_9bdf);
+            fast.write_i8(-2);
+            fast.write_i16(-3);
+            fast.write_i32(-4);
+            fast.write_i64(-5);
+            fast.write_i128(-6);
+            fast.write_isize(-7);
+
+            slow.write(&0x12u8.to_le_bytes());
+            slow.write(&0x3456u16.to_le_bytes());
+            slow.write(&0x789a_bcdeu32.to_le_bytes());
+            slow.write(&0x0123_4567_89ab_cdefu64.to_le_bytes());
+            slow.write(&0xfedc_ba98_7654_3210_0f1e_2d3c_4b5a_6978u128.to_le_bytes());
+            slow.write(&0x1357_9bdfusize.to_le_bytes());
+            slow.write(&(-2i8).to_le_bytes());
+            slow.write(&(-3i16).to_le_bytes());
+            slow.write(&(-4i32).to_le_bytes());
+            slow.write(&(-5i64).to_le_bytes());
+            slow.write(&(-6i128).to_le_bytes());
+            slow.write(&(-7isize).to_le_bytes());
+
+            assert_eq!(fast.finish_32(), slow.finish_32(), "lead {lead}");
+            assert_eq!(fast.finish(), slow.finish(), "lead {lead}");
+            fast.write(b"tail");
+            slow.write(b"tail");
+            assert_eq!(fast.finish_32(), slow.finish_32(), "lead {lead}");
+        }
+    }
+
+    #[test]
+    fn finish_mixes_top_bits() {
+        let mut tags = std::collections::HashSet::new();
+        for key in 0u32..1024 {
+            let mut hasher = Murmur3Hasher::new();
+            hasher.write_u32(key);
+            tags.insert(hasher.finish() >> 57);
+        }
+        assert_eq!(tags.len(), 128);
+    }
+
+    #[test]
+    fn streaming_matches_one_shot() {
+        let data: Vec<u8> = (0..300u32).map(|x| (x * 7 + 3) as u8).collect();
+        for len in [0usize, 1, 3, 4, 5, 31, 32, 63, 64, 65, 127, 128, 300] {
+            let expected = murmurhash3_32_with_seed(&data[..len], 0x9747b28c);
+            let expected_64 = murmurhash3_128_with_seed(&data[..len], 0x9747b28c) as u64;
+            for split in 1..=7 {
+                let mut hasher = Murmur3Hasher::new_with_seed(0x9747b28c);
+                for chunk in data[..len].chunks(split) {
+                    hasher.write(chunk);
+                }
+                assert_eq!(hasher.finish_32(), expected);
+                assert_eq!(hasher.finish(), expected_64);
+            }
+            let mut hasher = Murmur3Hasher::new_with_seed(0x9747b28c);
+            hasher.write(&data[..len / 3]);
+            hasher.write(&data[len / 3..len]);
+            assert_eq!(hasher.finish_32(), expected);
+            assert_eq!(hasher.finish(), expected_64);
+        }
+    }
+
+    #[test]
+    fn every_backend_matches_generic() {
+        let data: Vec<u8> = (0..300u32).map(|x| (x * 11 + 5) as u8).collect();
+        for backend in Backend::ALL {
+            if !backend.is_supported() {
+                assert_eq!(
+                    murmurhash3_32_with_backend(&data, 0, backend),
+                    Err(Unsupported { backend })
+                );
+                continue;
+            }
+            for len in 0..data.len() {
+                assert_eq!(
+                    murmurhash3_32_with_backend(&data[..len], 17, backend),
+                    Ok(crate::generic::murmurhash3_32(&data[..len], 17)),
+                    "{backend:?} at length {len}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn murmur2a_incremental_matches_one_shot() {
+        let data: Vec<u8> = (0..300u32).map(|x| (x * 5 + 1) as u8).collect();
+        for len in [0usize, 1, 2, 3, 4, 5, 15, 16, 17, 64, 65, 300] {
+            let expected = murmurhash2a_32_with_seed(&data[..len], 0x9747b28c);
+            for split in 1..=7 {
+                let mut hasher = Murmur2AHasher::new_with_seed(0x9747b28c);
+                for chunk in data[..len].chunks(split) {
+                    hasher.add(chunk);
+                }
+                assert_eq!(hasher.end(), expected);
+                assert_eq!(hasher.finish(), expected as u64);
+            }
+        }
+    }
+
+    #[test]
+    fn murmur2a_fields() {
+        let mut hasher = Murmur2AHasher::new();
+        hasher.add(b"2025-10-17T12:00:00Z");
+        hasher.add(b" ");
+        hasher.add(b"INFO");
+        hasher.add(b" request served");
+        assert_eq!(
+            hasher.end(),
+            murmurhash2a_32_with_seed(b"2025-10-17T12:00:00Z INFO request served", 0)
+        );
     }
 }
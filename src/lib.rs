@@ -27,18 +27,270 @@
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 
+/// Feeds integers to the hasher as little-endian bytes, so derived `Hash`
+/// implementations produce the same value on every target.
+macro_rules! le_integer_writes {
+    () => {
+        le_integer_writes!(
+            write_u16: u16, write_u32: u32, write_u64: u64, write_u128: u128, write_usize: usize,
+            write_i16: i16, write_i32: i32, write_i64: i64, write_i128: i128, write_isize: isize,
+        );
+    };
+    ($($name:ident: $ty:ty),* $(,)?) => {
+        $(
+            #[inline]
+            fn $name(&mut self, i: $ty) {
+                self.write(&i.to_le_bytes());
+            }
+        )*
+    };
+}
+
+/// Hashes a string literal with **MurmurHash3 (x86_32)** at compile time.
+///
+/// `mm3h!("literal")` uses seed `0`, `mm3h!("literal", seed)` the given
+/// seed, which must be a constant as well. The value is computed by
+/// [`murmurhash3_32_const`] in a `const` item, so it is guaranteed to be
+/// evaluated by the compiler and equals [`murmurhash3_32_with_seed`].
+///
+/// The `const fn` variants [`murmurhash3_32_const`], [`murmurhash2_64_const`]
+/// and [`murmurhash3_128_const`] share the block round and the finalization
+/// with their regular counterparts, so they always agree. At runtime prefer
+/// the regular functions, which are unrolled and SIMD accelerated.
+///
+/// ```
+/// use mm3h::mm3h;
+///
+/// const CLICK: u32 = mm3h!("click");
+///
+/// fn kind(tag: u32) -> &'static str {
+///     match tag {
+///         CLICK => "click",
+///         _ => "other",
+///     }
+/// }
+///
+/// assert_eq!(kind(mm3h::murmurhash3_32(b"click")), "click");
+/// assert_eq!(mm3h!("click", 42), mm3h::murmurhash3_32_with_seed(b"click", 42));
+/// ```
+#[macro_export]
+macro_rules! mm3h {
+    ($s:expr) => {
+        $crate::mm3h!($s, 0)
+    };
+    ($s:expr, $seed:expr $(,)?) => {{
+        const HASH: u32 = $crate::murmurhash3_32_const(::core::primitive::str::as_bytes($s), $seed);
+        HASH
+    }};
+}
+
+mod algorithm;
+mod backend;
+mod batch;
+mod buffer;
+mod build_hasher;
+mod column;
 mod generic;
 mod hasher;
 #[cfg(all(target_arch = "aarch64", feature = "neon"))]
 mod neon;
+mod output;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "sse"))]
 mod sse;
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128", feature = "wasm"))]
+mod wasm;
 
-#[cfg(all(target_arch = "x86_64", feature = "avx"))]
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx"))]
 mod avx;
+#[cfg(all(target_arch = "x86_64", feature = "avx512"))]
+#[clippy::msrv = "1.89"]
+mod avx512;
+mod generic1;
 mod generic128;
+mod generic128_x86;
+mod generic32;
 mod generic64;
 
-pub use generic64::{murmurhash2_64, murmurhash2_64_with_seed};
-pub use generic128::{murmurhash3_128, murmurhash3_128_with_seed};
-pub use hasher::{Murmur3Hasher, murmurhash3_32, murmurhash3_32_with_seed};
+pub use algorithm::{
+    BufferedStream, MurmurHash, MurmurHash1_32, MurmurHash2_32, MurmurHash2_64, MurmurHash2_64B,
+    MurmurHash2A, MurmurHash3_32, MurmurHash3_128, MurmurHash3X86_128, MurmurHashAligned2,
+    MurmurHashNeutral2, MurmurStream,
+};
+pub use backend::{Backend, Unsupported, active_backend, force_backend};
+pub use batch::{
+    murmurhash3_32_batch, murmurhash3_32_batch_fixed, murmurhash3_32_batch_fixed_with_backend,
+    murmurhash3_32_batch_with_backend,
+};
+pub use build_hasher::{
+    Murmur3BuildHasher, Murmur3HashMap, Murmur3HashSet, Murmur3RandomState,
+    Murmur3x64_128BuildHasher, Murmur3x64_128HashMap, Murmur3x64_128HashSet,
+    Murmur3x64_128RandomState, Murmur64ABuildHasher, Murmur64AHashMap, Murmur64AHashSet,
+    Murmur64ARandomState,
+};
+pub use column::{
+    hash_i64_column, hash_i64_column_64a, hash_i64_column_128, hash_u32_column,
+    hash_u32_column_64a, hash_u32_column_128, hash_u64_column, hash_u64_column_64a,
+    hash_u64_column_128,
+};
+pub use generic::murmurhash3_32_const;
+pub use generic1::{murmurhash1_32, murmurhash1_32_with_seed};
+pub use generic32::{
+    murmurhash_aligned2, murmurhash_aligned2_with_seed, murmurhash_neutral2,
+    murmurhash_neutral2_with_seed, murmurhash2_32, murmurhash2_32_with_seed, murmurhash2a_32,
+    murmurhash2a_32_with_seed,
+};
+pub use generic64::{
+    LengthMismatch, Murmur64AHasher, MurmurHash64AStream, murmurhash2_64, murmurhash2_64_const,
+    murmurhash2_64_with_seed, murmurhash2_64b, murmurhash2_64b_with_seed,
+};
+pub use generic128::{
+    Murmur3x64_128, murmurhash3_128, murmurhash3_128_const, murmurhash3_128_with_seed,
+};
+pub use generic128_x86::{Murmur3x86_128, murmurhash3_x86_128, murmurhash3_x86_128_with_seed};
+pub use hasher::{
+    Murmur2AHasher, Murmur3Hasher, murmurhash3_32, murmurhash3_32_with_backend,
+    murmurhash3_32_with_seed,
+};
+pub use output::{Hash32, Hash64, Hash128, ParseHashError};
+
+/// Reference values produced by the original SMHasher C implementation on a
+/// little-endian host. They must hold bit-for-bit on every target, in
+/// particular big-endian ones, so CI also runs this module under Miri on
+/// `s390x-unknown-linux-gnu`.
+#[cfg(test)]
+mod golden {
+    use super::*;
+
+    const INPUTS: [&str; 8] = [
+        "",
+        "1",
+        "12",
+        "123",
+        "1234",
+        "The quick brown fox jumps over the lazy dog",
+        "Rust high performace utilities for YUV format handling and conversion.",
+        "432432 gfdsafgsd 32432 fds",
+    ];
+
+    fn assert_golden<T: PartialEq + std::fmt::Debug>(hash: impl Fn(&[u8]) -> T, expected: &[T; 9]) {
+        let long: Vec<u8> = (0..300u32).map(|i| ((i * 7 + 3) % 255 + 1) as u8).collect();
+        let inputs = INPUTS.iter().map(|s| s.as_bytes()).chain([long.as_slice()]);
+        for (input, expected) in inputs.zip(expected.iter()) {
+            assert_eq!(&hash(input), expected, "input of {} bytes", input.len());
+        }
+    }
+
+    #[test]
+    fn test_golden_vectors() {
+        assert_golden(
+            |bytes| {
+                let hash = murmurhash3_32_with_seed(bytes, 0x9747b28c);
+                assert_eq!(murmurhash3_32_const(bytes, 0x9747b28c), hash);
+                hash
+            },
+            &[
+                3954623016, 1957450686, 2038725731, 3139744868, 2937635628, 799549133, 3800802887,
+                3402006676, 2745731247,
+            ],
+        );
+        assert_golden(
+            |bytes| murmurhash3_x86_128_with_seed(bytes, 0x9747b28c),
+            &[
+                0x5b576a1c_5b576a1c_5b576a1c_f7bed5a1,
+                0xe2a5ef3d_e2a5ef3d_e2a5ef3d_c1b9dfcb,
+                0xe378126f_e378126f_e378126f_6a52579a,
+                0xff09031a_ff09031a_ff09031a_2bd1104d,
+                0x559b799d_559b799d_559b799d_e71868c9,
+                0xcdb6793e_8ea73a9c_4cb86171_8ad4d55e,
+                0xf906b495_b3be7541_93b2dd3a_ab2cd1ac,
+                0x787bf640_161cd813_ca7ce27b_04ef177c,
+                0x017654ed_504ae7b7_fccaeba3_acfab5fc,
+            ],
+        );
+        assert_golden(
+            |bytes| {
+                let hash = murmurhash3_128_with_seed(bytes, 0x9747b28c);
+                assert_eq!(murmurhash3_128_const(bytes, 0x9747b28c), hash);
+                hash
+            },
+            &[
+                0x93b0608f_e302957a_392b208a_1daabbb3,
+                0x43532f9d_2478874d_5ae258eb_7e2d175d,
+                0xafe67347_043448ef_c56c5221_4bc7f056,
+                0x536ad57e_b65da206_b604b7f1_2aef6036,
+                0xe2a74419_6d33b4b6_4a929ba9_427d31d7,
+                0xf9457372_7ec016e5_738a7f3b_d2633121,
+                0x7eb15a3d_b4ec4092_0b906d59_2879cbee,
+                0xcaaeb61d_95ad6117_77d87ab5_3895abc6,
+                0xf3e63f1b_8acbec78_19419f4c_22ee80cd,
+            ],
+        );
+        assert_golden(
+            |bytes| {
+                let hash = murmurhash2_64_with_seed(bytes, 0x9747b28c);
+                assert_eq!(murmurhash2_64_const(bytes, 0x9747b28c), hash);
+                hash
+            },
+            &[
+                9482155760047181906,
+                2777624482744946707,
+                15031355454339350383,
+                16637712234032559274,
+                3831108515349844968,
+                187593484090523012,
+                13043567735639214812,
+                18344827072389557028,
+                7971904889476690462,
+            ],
+        );
+        assert_golden(
+            |bytes| murmurhash2_64b_with_seed(bytes, 0x9747b28c),
+            &[
+                377774710346637481,
+                18092033890849530865,
+                7665261928941895653,
+                5901898653772102698,
+                8751031338212675692,
+                2166401148549990189,
+                2263729065597448921,
+                14417758379871870087,
+                319921884142810159,
+            ],
+        );
+        assert_golden(
+            |bytes| murmurhash2_32_with_seed(bytes, 0x9747b28c),
+            &[
+                275646681, 2301521807, 126087238, 4027264813, 2680781588, 495243318, 1669195974,
+                3301404050, 1040483146,
+            ],
+        );
+        assert_golden(
+            |bytes| murmurhash2a_32_with_seed(bytes, 0x9747b28c),
+            &[
+                3816574809, 1677797097, 2776184697, 2199263488, 422956130, 3850411154, 2763301334,
+                2442028232, 1846722392,
+            ],
+        );
+        assert_golden(
+            |bytes| murmurhash_neutral2_with_seed(bytes, 0x9747b28c),
+            &[
+                275646681, 2301521807, 126087238, 4027264813, 2680781588, 495243318, 1669195974,
+                3301404050, 1040483146,
+            ],
+        );
+        assert_golden(
+            |bytes| murmurhash_aligned2_with_seed(bytes, 0x9747b28c),
+            &[
+                275646681, 2301521807, 126087238, 4027264813, 2680781588, 495243318, 1669195974,
+                3301404050, 1040483146,
+            ],
+        );
+        assert_golden(
+            |bytes| murmurhash1_32_with_seed(bytes, 0x9747b28c),
+            &[
+                1260253080, 2803304972, 2214015013, 561688391, 2109524390, 3950314481, 3807871493,
+                863180360, 2237721831,
+            ],
+        );
+    }
+}
@@ -0,0 +1,118 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 10/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::generic32::{M, mmix, read_le32};
+use crate::neon::vld1q_u32_le;
+use std::arch::aarch64::*;
+
+#[inline(always)]
+unsafe fn vmix_k(k: uint32x4_t) -> uint32x4_t {
+    unsafe {
+        let k = vmulq_n_u32(k, M);
+        let k = veorq_u32(k, vshrq_n_u32::<24>(k));
+        vmulq_n_u32(k, M)
+    }
+}
+
+pub(crate) fn neon_murmurhash2_32_blocks(bytes: &[u8], h: u32) -> u32 {
+    let mut h = h;
+
+    for chunk in bytes.chunks_exact(64) {
+        unsafe {
+            let mut k1 = vld1q_u32_le(chunk.as_ptr());
+            let mut k2 = vld1q_u32_le(chunk.get_unchecked(16..).as_ptr());
+            let mut k3 = vld1q_u32_le(chunk.get_unchecked(32..).as_ptr());
+            let mut k4 = vld1q_u32_le(chunk.get_unchecked(48..).as_ptr());
+
+            k1 = vmix_k(k1);
+            k2 = vmix_k(k2);
+            k3 = vmix_k(k3);
+            k4 = vmix_k(k4);
+
+            h = h.wrapping_mul(M) ^ vgetq_lane_u32::<0>(k1);
+            h = h.wrapping_mul(M) ^ vgetq_lane_u32::<1>(k1);
+            h = h.wrapping_mul(M) ^ vgetq_lane_u32::<2>(k1);
+            h = h.wrapping_mul(M) ^ vgetq_lane_u32::<3>(k1);
+
+            h = h.wrapping_mul(M) ^ vgetq_lane_u32::<0>(k2);
+            h = h.wrapping_mul(M) ^ vgetq_lane_u32::<1>(k2);
+            h = h.wrapping_mul(M) ^ vgetq_lane_u32::<2>(k2);
+            h = h.wrapping_mul(M) ^ vgetq_lane_u32::<3>(k2);
+
+            h = h.wrapping_mul(M) ^ vgetq_lane_u32::<0>(k3);
+            h = h.wrapping_mul(M) ^ vgetq_lane_u32::<1>(k3);
+            h = h.wrapping_mul(M) ^ vgetq_lane_u32::<2>(k3);
+            h = h.wrapping_mul(M) ^ vgetq_lane_u32::<3>(k3);
+
+            h = h.wrapping_mul(M) ^ vgetq_lane_u32::<0>(k4);
+            h = h.wrapping_mul(M) ^ vgetq_lane_u32::<1>(k4);
+            h = h.wrapping_mul(M) ^ vgetq_lane_u32::<2>(k4);
+            h = h.wrapping_mul(M) ^ vgetq_lane_u32::<3>(k4);
+        }
+    }
+
+    let rem = bytes.chunks_exact(64).remainder();
+
+    for chunk in rem.chunks_exact(16) {
+        unsafe {
+            let mut k1 = vld1q_u32_le(chunk.as_ptr());
+
+            k1 = vmix_k(k1);
+
+            h = h.wrapping_mul(M) ^ vgetq_lane_u32::<0>(k1);
+            h = h.wrapping_mul(M) ^ vgetq_lane_u32::<1>(k1);
+            h = h.wrapping_mul(M) ^ vgetq_lane_u32::<2>(k1);
+            h = h.wrapping_mul(M) ^ vgetq_lane_u32::<3>(k1);
+        }
+    }
+
+    let rem = rem.chunks_exact(16).remainder();
+
+    for chunk in rem.chunks_exact(4) {
+        h = mmix(h, read_le32(chunk));
+    }
+
+    h
+}
+
+#[cfg(test)]
+mod test {
+    use super::neon_murmurhash2_32_blocks;
+    use crate::generic32::murmurhash2_32_blocks;
+
+    #[test]
+    fn test_matches_generic() {
+        let data: Vec<u8> = (0..300u32).map(|x| (x * 19 + 5) as u8).collect();
+        for len in 0..data.len() {
+            assert_eq!(
+                neon_murmurhash2_32_blocks(&data[..len], len as u32),
+                murmurhash2_32_blocks(&data[..len], len as u32)
+            );
+        }
+    }
+}
@@ -0,0 +1,146 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 10/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::generic128_x86::{C1, C2, C3, C4, murmurhash3_x86_128_round};
+use crate::neon::vld1q_u32_le;
+use std::arch::aarch64::*;
+
+#[inline(always)]
+unsafe fn vrotate_lanes(v: uint32x4_t, left: int32x4_t, right: int32x4_t) -> uint32x4_t {
+    unsafe { vorrq_u32(vshlq_u32(v, left), vshlq_u32(v, right)) }
+}
+
+pub(crate) fn neon_murmurhash3_x86_128_blocks(bytes: &[u8], h: [u32; 4]) -> [u32; 4] {
+    let mut h = h;
+
+    unsafe {
+        let m1 = vld1q_u32([C1, C2, C3, C4].as_ptr());
+        let m2 = vld1q_u32([C2, C3, C4, C1].as_ptr());
+        let left = vld1q_s32([15, 16, 17, 18].as_ptr());
+        let right = vld1q_s32([-17, -16, -15, -14].as_ptr());
+
+        for chunk in bytes.chunks_exact(64) {
+            let mut k1 = vld1q_u32_le(chunk.as_ptr());
+            let mut k2 = vld1q_u32_le(chunk.get_unchecked(16..).as_ptr());
+            let mut k3 = vld1q_u32_le(chunk.get_unchecked(32..).as_ptr());
+            let mut k4 = vld1q_u32_le(chunk.get_unchecked(48..).as_ptr());
+
+            k1 = vmulq_u32(k1, m1);
+            k2 = vmulq_u32(k2, m1);
+            k3 = vmulq_u32(k3, m1);
+            k4 = vmulq_u32(k4, m1);
+
+            k1 = vrotate_lanes(k1, left, right);
+            k2 = vrotate_lanes(k2, left, right);
+            k3 = vrotate_lanes(k3, left, right);
+            k4 = vrotate_lanes(k4, left, right);
+
+            k1 = vmulq_u32(k1, m2);
+            k2 = vmulq_u32(k2, m2);
+            k3 = vmulq_u32(k3, m2);
+            k4 = vmulq_u32(k4, m2);
+
+            h = murmurhash3_x86_128_round(
+                h,
+                [
+                    vgetq_lane_u32::<0>(k1),
+                    vgetq_lane_u32::<1>(k1),
+                    vgetq_lane_u32::<2>(k1),
+                    vgetq_lane_u32::<3>(k1),
+                ],
+            );
+            h = murmurhash3_x86_128_round(
+                h,
+                [
+                    vgetq_lane_u32::<0>(k2),
+                    vgetq_lane_u32::<1>(k2),
+                    vgetq_lane_u32::<2>(k2),
+                    vgetq_lane_u32::<3>(k2),
+                ],
+            );
+            h = murmurhash3_x86_128_round(
+                h,
+                [
+                    vgetq_lane_u32::<0>(k3),
+                    vgetq_lane_u32::<1>(k3),
+                    vgetq_lane_u32::<2>(k3),
+                    vgetq_lane_u32::<3>(k3),
+                ],
+            );
+            h = murmurhash3_x86_128_round(
+                h,
+                [
+                    vgetq_lane_u32::<0>(k4),
+                    vgetq_lane_u32::<1>(k4),
+                    vgetq_lane_u32::<2>(k4),
+                    vgetq_lane_u32::<3>(k4),
+                ],
+            );
+        }
+
+        let rem = bytes.chunks_exact(64).remainder();
+
+        for chunk in rem.chunks_exact(16) {
+            let mut k1 = vld1q_u32_le(chunk.as_ptr());
+
+            k1 = vmulq_u32(k1, m1);
+            k1 = vrotate_lanes(k1, left, right);
+            k1 = vmulq_u32(k1, m2);
+
+            h = murmurhash3_x86_128_round(
+                h,
+                [
+                    vgetq_lane_u32::<0>(k1),
+                    vgetq_lane_u32::<1>(k1),
+                    vgetq_lane_u32::<2>(k1),
+                    vgetq_lane_u32::<3>(k1),
+                ],
+            );
+        }
+    }
+
+    h
+}
+
+#[cfg(test)]
+mod test {
+    use super::neon_murmurhash3_x86_128_blocks;
+    use crate::generic128_x86::murmurhash3_x86_128_blocks;
+
+    #[test]
+    fn test_matches_generic() {
+        let data: Vec<u8> = (0..300u32).map(|x| (x * 17 + 3) as u8).collect();
+        for len in 0..data.len() {
+            let seed = [len as u32, 1, 2, 0x9747b28c];
+            assert_eq!(
+                neon_murmurhash3_x86_128_blocks(&data[..len], seed),
+                murmurhash3_x86_128_blocks(&data[..len], seed)
+            );
+        }
+    }
+}
@@ -26,7 +26,8 @@
  * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
-use crate::generic::scramble;
+use crate::generic::murmurhash3_32_finalize;
+use crate::neon::vld1q_u32_le;
 use std::arch::aarch64::*;
 
 #[inline(always)]
@@ -35,21 +36,22 @@ unsafe fn vrotate_left15(v: uint32x4_t) -> uint32x4_t {
 }
 
 pub(crate) fn neon_murmurhash3_32(bytes: &[u8], seed: u32) -> u32 {
+    let h1 = neon_murmurhash3_32_blocks(bytes, seed);
+    murmurhash3_32_finalize(bytes.chunks_exact(4).remainder(), h1, bytes.len())
+}
+
+pub(crate) fn neon_murmurhash3_32_blocks(bytes: &[u8], h1: u32) -> u32 {
     const C1: u32 = 0xcc9e2d51u32;
     const C2: u32 = 0x1b873593u32;
 
-    let read_size = 4;
-    let len = bytes.len() as u32;
-    let block_count = len / read_size;
-
-    let mut h1 = seed;
+    let mut h1 = h1;
 
     for chunk in bytes.chunks_exact(64) {
         unsafe {
-            let mut k1 = vld1q_u32(chunk.as_ptr() as *const u32);
-            let mut k2 = vld1q_u32(chunk.get_unchecked(16..).as_ptr() as *const u32);
-            let mut k3 = vld1q_u32(chunk.get_unchecked(32..).as_ptr() as *const u32);
-            let mut k4 = vld1q_u32(chunk.get_unchecked(48..).as_ptr() as *const u32);
+            let mut k1 = vld1q_u32_le(chunk.as_ptr());
+            let mut k2 = vld1q_u32_le(chunk.get_unchecked(16..).as_ptr());
+            let mut k3 = vld1q_u32_le(chunk.get_unchecked(32..).as_ptr());
+            let mut k4 = vld1q_u32_le(chunk.get_unchecked(48..).as_ptr());
 
             k1 = vmulq_n_u32(k1, C1);
             k2 = vmulq_n_u32(k2, C1);
@@ -152,8 +154,8 @@ pub(crate) fn neon_murmurhash3_32(bytes: &[u8], seed: u32) -> u32 {
 
     for chunk in rem.chunks_exact(32) {
         unsafe {
-            let mut k1 = vld1q_u32(chunk.as_ptr() as *const u32);
-            let mut k2 = vld1q_u32(chunk.get_unchecked(16..).as_ptr() as *const u32);
+            let mut k1 = vld1q_u32_le(chunk.as_ptr());
+            let mut k2 = vld1q_u32_le(chunk.get_unchecked(16..).as_ptr());
 
             k1 = vmulq_n_u32(k1, C1);
             k2 = vmulq_n_u32(k2, C1);
@@ -209,7 +211,7 @@ pub(crate) fn neon_murmurhash3_32(bytes: &[u8], seed: u32) -> u32 {
     let rem = rem.chunks_exact(32).remainder();
 
     for chunk in rem.chunks_exact(4) {
-        let mut k1 = u32::from_ne_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]).to_le();
+        let mut k1 = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
 
         k1 = k1.wrapping_mul(C1);
         k1 = k1.rotate_left(15);
@@ -221,25 +223,6 @@ pub(crate) fn neon_murmurhash3_32(bytes: &[u8], seed: u32) -> u32 {
         h1 = h1.wrapping_add(0xe6546b64)
     }
 
-    let mut k1 = 0u32;
-
-    if len & 3 == 3 {
-        k1 ^= (bytes[(block_count * read_size) as usize + 2] as u32) << 16;
-    }
-    if len & 3 >= 2 {
-        k1 ^= (bytes[(block_count * read_size) as usize + 1] as u32) << 8;
-    }
-    if len & 3 >= 1 {
-        k1 ^= bytes[(block_count * read_size) as usize] as u32;
-        k1 = k1.wrapping_mul(C1);
-        k1 = k1.rotate_left(15);
-        k1 = k1.wrapping_mul(C2);
-        h1 ^= k1;
-    }
-
-    h1 ^= bytes.len() as u32;
-    h1 = scramble(h1);
-
     h1
 }
 
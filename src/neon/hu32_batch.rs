@@ -0,0 +1,133 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 10/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::batch::{
+    hash_lane_groups, lane_active, lane_blocks, lane_lens, lane_tails, lane_words,
+    lane_words_unchecked,
+};
+use std::arch::aarch64::*;
+
+#[inline(always)]
+unsafe fn vld1q_lanes(v: &[u32; 4]) -> uint32x4_t {
+    unsafe { vld1q_u32(v.as_ptr()) }
+}
+
+#[inline(always)]
+unsafe fn vmix_k1(k1: uint32x4_t) -> uint32x4_t {
+    const C1: u32 = 0xcc9e2d51u32;
+    const C2: u32 = 0x1b873593u32;
+
+    unsafe {
+        let k1 = vmulq_n_u32(k1, C1);
+        let k1 = vsliq_n_u32::<15>(vshrq_n_u32::<17>(k1), k1);
+        vmulq_n_u32(k1, C2)
+    }
+}
+
+#[inline(always)]
+unsafe fn vmix_h1(h1: uint32x4_t, k1: uint32x4_t) -> uint32x4_t {
+    unsafe {
+        let h1 = veorq_u32(h1, k1);
+        let h1 = vsliq_n_u32::<13>(vshrq_n_u32::<19>(h1), h1);
+        vaddq_u32(vmulq_n_u32(h1, 5), vdupq_n_u32(0xe6546b64))
+    }
+}
+
+fn neon_murmurhash3_32_batch4(keys: &[&[u8]; 4], seed: u32) -> [u32; 4] {
+    let blocks = lane_blocks(keys);
+    let min_blocks = blocks.iter().copied().min().unwrap_or(0);
+    let max_blocks = blocks.iter().copied().max().unwrap_or(0);
+
+    unsafe {
+        let mut h1 = vdupq_n_u32(seed);
+
+        for i in 0..min_blocks {
+            let k1 = vld1q_lanes(&lane_words_unchecked(keys, i));
+            h1 = vmix_h1(h1, vmix_k1(k1));
+        }
+
+        for i in min_blocks..max_blocks {
+            let active = vld1q_lanes(&lane_active(&blocks, i));
+            let k1 = vld1q_lanes(&lane_words(keys, i));
+            let mixed = vmix_h1(h1, vmix_k1(k1));
+            h1 = vbslq_u32(active, mixed, h1);
+        }
+
+        // A zero tail word mixes to zero, so lanes without a tail are untouched.
+        let tail = vld1q_lanes(&lane_tails(keys));
+        h1 = veorq_u32(h1, vmix_k1(tail));
+
+        h1 = veorq_u32(h1, vld1q_lanes(&lane_lens(keys)));
+
+        h1 = veorq_u32(h1, vshrq_n_u32::<16>(h1));
+        h1 = vmulq_n_u32(h1, 0x85ebca6b);
+        h1 = veorq_u32(h1, vshrq_n_u32::<13>(h1));
+        h1 = vmulq_n_u32(h1, 0xc2b2ae35);
+        h1 = veorq_u32(h1, vshrq_n_u32::<16>(h1));
+
+        let mut out = [0u32; 4];
+        vst1q_u32(out.as_mut_ptr(), h1);
+        out
+    }
+}
+
+pub(crate) fn neon_murmurhash3_32_batch(keys: &[&[u8]], seed: u32, out: &mut [u32]) {
+    hash_lane_groups(keys.iter().copied(), out, |group| {
+        neon_murmurhash3_32_batch4(group, seed)
+    });
+}
+
+pub(crate) fn neon_murmurhash3_32_batch_fixed(
+    keys: &[u8],
+    key_len: usize,
+    seed: u32,
+    out: &mut [u32],
+) {
+    hash_lane_groups(keys.chunks_exact(key_len), out, |group| {
+        neon_murmurhash3_32_batch4(group, seed)
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::neon_murmurhash3_32_batch;
+    use crate::generic::murmurhash3_32;
+
+    #[test]
+    fn test_matches_generic() {
+        let owned: Vec<Vec<u8>> = (0..131u32)
+            .map(|i| (0..(i * 5) % 67).map(|x| (x * 17 + i) as u8).collect())
+            .collect();
+        let keys: Vec<&[u8]> = owned.iter().map(|k| k.as_slice()).collect();
+        let expected: Vec<u32> = keys.iter().map(|k| murmurhash3_32(k, 7)).collect();
+
+        let mut out = vec![0u32; keys.len()];
+        neon_murmurhash3_32_batch(&keys, 7, &mut out);
+        assert_eq!(out, expected);
+    }
+}
@@ -26,6 +26,26 @@
  * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
+mod h2u32;
+mod hu128;
 mod hu32;
+mod hu32_batch;
 
-pub(crate) use hu32::neon_murmurhash3_32;
+pub(crate) use h2u32::neon_murmurhash2_32_blocks;
+pub(crate) use hu32::{neon_murmurhash3_32, neon_murmurhash3_32_blocks};
+pub(crate) use hu32_batch::{neon_murmurhash3_32_batch, neon_murmurhash3_32_batch_fixed};
+pub(crate) use hu128::neon_murmurhash3_x86_128_blocks;
+
+use std::arch::aarch64::*;
+
+/// Loads four `u32` lanes stored as little-endian bytes, regardless of the
+/// target byte order.
+#[inline(always)]
+pub(crate) unsafe fn vld1q_u32_le(ptr: *const u8) -> uint32x4_t {
+    unsafe {
+        let v = vld1q_u8(ptr);
+        #[cfg(target_endian = "big")]
+        let v = vrev32q_u8(v);
+        vreinterpretq_u32_u8(v)
+    }
+}
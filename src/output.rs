@@ -0,0 +1,312 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 10/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use std::fmt;
+use std::str::FromStr;
+
+/// Error returned when parsing a hash value from a hex string fails.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ParseHashError {
+    /// The string does not have exactly two hex digits per byte.
+    InvalidLength {
+        /// The number of hex digits required.
+        expected: usize,
+        /// The number of hex digits found in the string.
+        actual: usize,
+    },
+    /// The string contains a character that is not a hex digit.
+    InvalidDigit {
+        /// Byte offset of the offending character.
+        position: usize,
+    },
+}
+
+impl fmt::Display for ParseHashError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseHashError::InvalidLength { expected, actual } => {
+                write!(f, "expected {expected} hex digits, found {actual}")
+            }
+            ParseHashError::InvalidDigit { position } => {
+                write!(f, "invalid hex digit at position {position}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseHashError {}
+
+fn hex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Decodes `s` as exactly `N` bytes written as hex digit pairs.
+fn parse_hex<const N: usize>(s: &str) -> Result<[u8; N], ParseHashError> {
+    let digits = s.as_bytes();
+    if digits.len() != 2 * N {
+        return Err(ParseHashError::InvalidLength {
+            expected: 2 * N,
+            actual: digits.len(),
+        });
+    }
+    let mut bytes = [0u8; N];
+    for (i, pair) in digits.chunks_exact(2).enumerate() {
+        let hi = hex_digit(pair[0]).ok_or(ParseHashError::InvalidDigit { position: 2 * i })?;
+        let lo = hex_digit(pair[1]).ok_or(ParseHashError::InvalidDigit {
+            position: 2 * i + 1,
+        })?;
+        bytes[i] = (hi << 4) | lo;
+    }
+    Ok(bytes)
+}
+
+macro_rules! hash_value {
+    ($(#[$doc:meta])* $name:ident, $int:ty, $len:literal) => {
+        $(#[$doc])*
+        ///
+        /// The canonical encoding is the little-endian byte order of the
+        /// value, which is what the reference C++ implementation writes to
+        /// its output buffer. [`Display`](fmt::Display) prints those bytes
+        /// as lowercase hex and [`FromStr`] parses them back, accepting
+        /// either case.
+        #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name(pub $int);
+
+        impl $name {
+            #[doc = concat!("Returns the ", stringify!($len), " canonical little-endian bytes of the hash.")]
+            pub const fn to_canonical_bytes(self) -> [u8; $len] {
+                self.0.to_le_bytes()
+            }
+
+            /// Creates the hash from its canonical little-endian bytes.
+            pub const fn from_canonical_bytes(bytes: [u8; $len]) -> Self {
+                Self(<$int>::from_le_bytes(bytes))
+            }
+        }
+
+        impl From<$int> for $name {
+            fn from(value: $int) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<$name> for $int {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                for byte in self.to_canonical_bytes() {
+                    write!(f, "{byte:02x}")?;
+                }
+                Ok(())
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseHashError;
+
+            fn from_str(s: &str) -> Result<Self, ParseHashError> {
+                parse_hex::<$len>(s).map(Self::from_canonical_bytes)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                if serializer.is_human_readable() {
+                    serializer.collect_str(self)
+                } else {
+                    serializer.serialize_bytes(&self.to_canonical_bytes())
+                }
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct Visitor;
+
+                impl serde::de::Visitor<'_> for Visitor {
+                    type Value = $name;
+
+                    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        write!(f, "{} hex digits or {} bytes", 2 * $len, $len)
+                    }
+
+                    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<$name, E> {
+                        v.parse().map_err(E::custom)
+                    }
+
+                    fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<$name, E> {
+                        let bytes = v
+                            .try_into()
+                            .map_err(|_| E::invalid_length(v.len(), &self))?;
+                        Ok($name::from_canonical_bytes(bytes))
+                    }
+                }
+
+                if deserializer.is_human_readable() {
+                    deserializer.deserialize_str(Visitor)
+                } else {
+                    deserializer.deserialize_bytes(Visitor)
+                }
+            }
+        }
+    };
+}
+
+hash_value!(
+    /// A 32-bit hash value that displays as its little-endian bytes in hex,
+    /// e.g. `Hash32(0x1234abcd)` prints `cdab3412`, not the `{:08x}` of the integer.
+    ///
+    /// It holds the result of the 32-bit MurmurHash variants.
+    Hash32, u32, 4
+);
+hash_value!(
+    /// A 64-bit hash value that displays as its little-endian bytes in hex,
+    /// e.g. `Hash64(0x0123456789abcdef)` prints `efcdab8967452301`, not the
+    /// `{:016x}` of the integer.
+    ///
+    /// It holds the result of MurmurHash64A and MurmurHash64B.
+    Hash64, u64, 8
+);
+hash_value!(
+    /// A 128-bit hash value that displays as its little-endian bytes in hex,
+    /// not the `{:032x}` of the integer.
+    ///
+    /// It holds the result of the 128-bit MurmurHash3 variants.
+    ///
+    /// For x64_128 the canonical bytes are `h1` followed by `h2`, each
+    /// little-endian, matching the output buffer of `MurmurHash3_x64_128`.
+    Hash128, u128, 16
+);
+
+impl Hash128 {
+    /// Returns the upper 64 bits, `h2` of MurmurHash3 x64_128.
+    pub const fn high64(self) -> u64 {
+        (self.0 >> 64) as u64
+    }
+
+    /// Returns the lower 64 bits, `h1` of MurmurHash3 x64_128.
+    pub const fn low64(self) -> u64 {
+        self.0 as u64
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn canonical_bytes_match_reference_layout() {
+        let hash = Hash128(crate::murmurhash3_128_with_seed(b"hello", 0));
+        let bytes = hash.to_canonical_bytes();
+        assert_eq!(bytes[..8], hash.low64().to_le_bytes());
+        assert_eq!(bytes[8..], hash.high64().to_le_bytes());
+        assert_eq!(Hash128::from_canonical_bytes(bytes), hash);
+        // The `out` buffer of `MurmurHash3_x64_128("hello", 5, 0, out)`.
+        assert_eq!(hash.to_string(), "029bbd41b3a7d8cb191dae486a901e5b");
+
+        assert_eq!(
+            Hash32(0x1234_5678).to_canonical_bytes(),
+            [0x78, 0x56, 0x34, 0x12]
+        );
+        assert_eq!(
+            Hash64::from_canonical_bytes([1, 0, 0, 0, 0, 0, 0, 0]),
+            Hash64(1)
+        );
+    }
+
+    #[test]
+    fn hex_round_trip() {
+        let hash = Hash32(0x1234_abcd);
+        assert_eq!(hash.to_string(), "cdab3412");
+        assert_eq!("cdab3412".parse(), Ok(hash));
+        assert_eq!("CDAB3412".parse(), Ok(hash));
+
+        let hash = Hash64(0x0123_4567_89ab_cdef);
+        assert_eq!(hash.to_string().parse(), Ok(hash));
+
+        let hash = Hash128(u128::MAX / 3);
+        assert_eq!(hash.to_string().parse(), Ok(hash));
+    }
+
+    #[test]
+    fn display_is_little_endian_bytes() {
+        assert_eq!(Hash32(0x1234_abcd).to_string(), "cdab3412");
+        assert_ne!(
+            Hash32(0x1234_abcd).to_string(),
+            format!("{:08x}", 0x1234_abcdu32)
+        );
+        assert_eq!(
+            Hash64(0x0123_4567_89ab_cdef).to_string(),
+            "efcdab8967452301"
+        );
+        assert_eq!(
+            Hash128(0x0011_2233_4455_6677_8899_aabb_ccdd_eeff).to_string(),
+            "ffeeddccbbaa99887766554433221100"
+        );
+    }
+
+    #[test]
+    fn hex_errors() {
+        assert_eq!(
+            "abc".parse::<Hash32>(),
+            Err(ParseHashError::InvalidLength {
+                expected: 8,
+                actual: 3
+            })
+        );
+        assert_eq!(
+            "0123456g".parse::<Hash32>(),
+            Err(ParseHashError::InvalidDigit { position: 7 })
+        );
+        assert_eq!(
+            "+1234567".parse::<Hash32>(),
+            Err(ParseHashError::InvalidDigit { position: 0 })
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        use serde_test::{Configure, Token, assert_tokens};
+
+        let hash = Hash32(0x1234_abcd);
+        assert_tokens(&hash.readable(), &[Token::Str("cdab3412")]);
+        assert_tokens(&hash.compact(), &[Token::Bytes(&[0xcd, 0xab, 0x34, 0x12])]);
+    }
+}
@@ -0,0 +1,126 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 10/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::generic32::{M, mmix, read_le32};
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+unsafe fn _mm_mix_k(k: __m128i, m: __m128i) -> __m128i {
+    let k = _mm_mullo_epi32(k, m);
+    let k = _mm_xor_si128(k, _mm_srli_epi32::<24>(k));
+    _mm_mullo_epi32(k, m)
+}
+
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn sse_murmurhash2_32_blocks(bytes: &[u8], h: u32) -> u32 {
+    let mut h = h;
+
+    let m = _mm_set1_epi32(M as i32);
+
+    for chunk in bytes.chunks_exact(64) {
+        unsafe {
+            let mut k1 = _mm_loadu_si128(chunk.as_ptr().cast());
+            let mut k2 = _mm_loadu_si128(chunk.get_unchecked(16..).as_ptr().cast());
+            let mut k3 = _mm_loadu_si128(chunk.get_unchecked(32..).as_ptr().cast());
+            let mut k4 = _mm_loadu_si128(chunk.get_unchecked(48..).as_ptr().cast());
+
+            k1 = _mm_mix_k(k1, m);
+            k2 = _mm_mix_k(k2, m);
+            k3 = _mm_mix_k(k3, m);
+            k4 = _mm_mix_k(k4, m);
+
+            h = h.wrapping_mul(M) ^ _mm_extract_epi32::<0>(k1) as u32;
+            h = h.wrapping_mul(M) ^ _mm_extract_epi32::<1>(k1) as u32;
+            h = h.wrapping_mul(M) ^ _mm_extract_epi32::<2>(k1) as u32;
+            h = h.wrapping_mul(M) ^ _mm_extract_epi32::<3>(k1) as u32;
+
+            h = h.wrapping_mul(M) ^ _mm_extract_epi32::<0>(k2) as u32;
+            h = h.wrapping_mul(M) ^ _mm_extract_epi32::<1>(k2) as u32;
+            h = h.wrapping_mul(M) ^ _mm_extract_epi32::<2>(k2) as u32;
+            h = h.wrapping_mul(M) ^ _mm_extract_epi32::<3>(k2) as u32;
+
+            h = h.wrapping_mul(M) ^ _mm_extract_epi32::<0>(k3) as u32;
+            h = h.wrapping_mul(M) ^ _mm_extract_epi32::<1>(k3) as u32;
+            h = h.wrapping_mul(M) ^ _mm_extract_epi32::<2>(k3) as u32;
+            h = h.wrapping_mul(M) ^ _mm_extract_epi32::<3>(k3) as u32;
+
+            h = h.wrapping_mul(M) ^ _mm_extract_epi32::<0>(k4) as u32;
+            h = h.wrapping_mul(M) ^ _mm_extract_epi32::<1>(k4) as u32;
+            h = h.wrapping_mul(M) ^ _mm_extract_epi32::<2>(k4) as u32;
+            h = h.wrapping_mul(M) ^ _mm_extract_epi32::<3>(k4) as u32;
+        }
+    }
+
+    let rem = bytes.chunks_exact(64).remainder();
+
+    for chunk in rem.chunks_exact(16) {
+        unsafe {
+            let mut k1 = _mm_loadu_si128(chunk.as_ptr().cast());
+
+            k1 = _mm_mix_k(k1, m);
+
+            h = h.wrapping_mul(M) ^ _mm_extract_epi32::<0>(k1) as u32;
+            h = h.wrapping_mul(M) ^ _mm_extract_epi32::<1>(k1) as u32;
+            h = h.wrapping_mul(M) ^ _mm_extract_epi32::<2>(k1) as u32;
+            h = h.wrapping_mul(M) ^ _mm_extract_epi32::<3>(k1) as u32;
+        }
+    }
+
+    let rem = rem.chunks_exact(16).remainder();
+
+    for chunk in rem.chunks_exact(4) {
+        h = mmix(h, read_le32(chunk));
+    }
+
+    h
+}
+
+#[cfg(test)]
+mod test {
+    use super::sse_murmurhash2_32_blocks;
+    use crate::generic32::murmurhash2_32_blocks;
+
+    #[test]
+    fn test_matches_generic() {
+        if std::arch::is_x86_feature_detected!("sse4.1") {
+            let data: Vec<u8> = (0..300u32).map(|x| (x * 19 + 5) as u8).collect();
+            for len in 0..data.len() {
+                unsafe {
+                    assert_eq!(
+                        sse_murmurhash2_32_blocks(&data[..len], len as u32),
+                        murmurhash2_32_blocks(&data[..len], len as u32)
+                    );
+                }
+            }
+        }
+    }
+}
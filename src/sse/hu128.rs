@@ -0,0 +1,167 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 10/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::generic128_x86::{C1, C2, C3, C4, murmurhash3_x86_128_round};
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Rotates lanes left by 15, 16, 17 and 18 bits respectively.
+///
+/// SSE has no per-lane variable shift, so the left part is produced by
+/// multiplying with the matching powers of two and the right part is
+/// blended together from four immediate shifts.
+#[inline]
+#[target_feature(enable = "sse4.1")]
+unsafe fn _mm_rotate_lanes(v: __m128i, left: __m128i) -> __m128i {
+    let l = _mm_mullo_epi32(v, left);
+    let r17 = _mm_srli_epi32::<17>(v);
+    let r16 = _mm_srli_epi32::<16>(v);
+    let r15 = _mm_srli_epi32::<15>(v);
+    let r14 = _mm_srli_epi32::<14>(v);
+    let lo = _mm_blend_epi16::<0x0C>(r17, r16);
+    let hi = _mm_blend_epi16::<0xC0>(r15, r14);
+    _mm_or_si128(l, _mm_blend_epi16::<0xF0>(lo, hi))
+}
+
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn sse_murmurhash3_x86_128_blocks(bytes: &[u8], h: [u32; 4]) -> [u32; 4] {
+    let mut h = h;
+
+    let m1 = _mm_setr_epi32(C1 as i32, C2 as i32, C3 as i32, C4 as i32);
+    let m2 = _mm_setr_epi32(C2 as i32, C3 as i32, C4 as i32, C1 as i32);
+    let left = _mm_setr_epi32(1 << 15, 1 << 16, 1 << 17, 1 << 18);
+
+    for chunk in bytes.chunks_exact(64) {
+        unsafe {
+            let mut k1 = _mm_loadu_si128(chunk.as_ptr().cast());
+            let mut k2 = _mm_loadu_si128(chunk.get_unchecked(16..).as_ptr().cast());
+            let mut k3 = _mm_loadu_si128(chunk.get_unchecked(32..).as_ptr().cast());
+            let mut k4 = _mm_loadu_si128(chunk.get_unchecked(48..).as_ptr().cast());
+
+            k1 = _mm_mullo_epi32(k1, m1);
+            k2 = _mm_mullo_epi32(k2, m1);
+            k3 = _mm_mullo_epi32(k3, m1);
+            k4 = _mm_mullo_epi32(k4, m1);
+
+            k1 = _mm_rotate_lanes(k1, left);
+            k2 = _mm_rotate_lanes(k2, left);
+            k3 = _mm_rotate_lanes(k3, left);
+            k4 = _mm_rotate_lanes(k4, left);
+
+            k1 = _mm_mullo_epi32(k1, m2);
+            k2 = _mm_mullo_epi32(k2, m2);
+            k3 = _mm_mullo_epi32(k3, m2);
+            k4 = _mm_mullo_epi32(k4, m2);
+
+            h = murmurhash3_x86_128_round(
+                h,
+                [
+                    _mm_extract_epi32::<0>(k1) as u32,
+                    _mm_extract_epi32::<1>(k1) as u32,
+                    _mm_extract_epi32::<2>(k1) as u32,
+                    _mm_extract_epi32::<3>(k1) as u32,
+                ],
+            );
+            h = murmurhash3_x86_128_round(
+                h,
+                [
+                    _mm_extract_epi32::<0>(k2) as u32,
+                    _mm_extract_epi32::<1>(k2) as u32,
+                    _mm_extract_epi32::<2>(k2) as u32,
+                    _mm_extract_epi32::<3>(k2) as u32,
+                ],
+            );
+            h = murmurhash3_x86_128_round(
+                h,
+                [
+                    _mm_extract_epi32::<0>(k3) as u32,
+                    _mm_extract_epi32::<1>(k3) as u32,
+                    _mm_extract_epi32::<2>(k3) as u32,
+                    _mm_extract_epi32::<3>(k3) as u32,
+                ],
+            );
+            h = murmurhash3_x86_128_round(
+                h,
+                [
+                    _mm_extract_epi32::<0>(k4) as u32,
+                    _mm_extract_epi32::<1>(k4) as u32,
+                    _mm_extract_epi32::<2>(k4) as u32,
+                    _mm_extract_epi32::<3>(k4) as u32,
+                ],
+            );
+        }
+    }
+
+    let rem = bytes.chunks_exact(64).remainder();
+
+    for chunk in rem.chunks_exact(16) {
+        unsafe {
+            let mut k1 = _mm_loadu_si128(chunk.as_ptr().cast());
+
+            k1 = _mm_mullo_epi32(k1, m1);
+            k1 = _mm_rotate_lanes(k1, left);
+            k1 = _mm_mullo_epi32(k1, m2);
+
+            h = murmurhash3_x86_128_round(
+                h,
+                [
+                    _mm_extract_epi32::<0>(k1) as u32,
+                    _mm_extract_epi32::<1>(k1) as u32,
+                    _mm_extract_epi32::<2>(k1) as u32,
+                    _mm_extract_epi32::<3>(k1) as u32,
+                ],
+            );
+        }
+    }
+
+    h
+}
+
+#[cfg(test)]
+mod test {
+    use super::sse_murmurhash3_x86_128_blocks;
+    use crate::generic128_x86::murmurhash3_x86_128_blocks;
+
+    #[test]
+    fn test_matches_generic() {
+        if std::arch::is_x86_feature_detected!("sse4.1") {
+            let data: Vec<u8> = (0..300u32).map(|x| (x * 17 + 3) as u8).collect();
+            for len in 0..data.len() {
+                let seed = [len as u32, 1, 2, 0x9747b28c];
+                unsafe {
+                    assert_eq!(
+                        sse_murmurhash3_x86_128_blocks(&data[..len], seed),
+                        murmurhash3_x86_128_blocks(&data[..len], seed)
+                    );
+                }
+            }
+        }
+    }
+}
@@ -26,33 +26,83 @@
  * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
-use crate::generic::scramble;
+use crate::generic::murmurhash3_32_finalize;
 #[cfg(target_arch = "x86")]
 use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
-#[inline]
-#[target_feature(enable = "sse2")]
+#[inline(always)]
 unsafe fn _mm_rotate_left15(v: __m128i) -> __m128i {
-    let left = _mm_slli_epi32::<15>(v);
-    let right = _mm_srli_epi32::<17>(v);
-    _mm_or_si128(left, right)
+    unsafe {
+        let left = _mm_slli_epi32::<15>(v);
+        let right = _mm_srli_epi32::<17>(v);
+        _mm_or_si128(left, right)
+    }
+}
+
+/// 32-bit lane-wise multiply, keeping the low half of each product.
+///
+/// `pmulld` is an SSE4.1 instruction; on plain SSE2 it is emulated with two
+/// `pmuludq` on the even and odd lanes followed by shuffles.
+#[inline(always)]
+pub(super) unsafe fn _mm_mullo_epi32x<const SSE4_1: bool>(a: __m128i, b: __m128i) -> __m128i {
+    unsafe {
+        if SSE4_1 {
+            _mm_mullo_epi32(a, b)
+        } else {
+            let even = _mm_mul_epu32(a, b);
+            let odd = _mm_mul_epu32(_mm_srli_epi64::<32>(a), _mm_srli_epi64::<32>(b));
+            _mm_unpacklo_epi32(
+                _mm_shuffle_epi32::<0b00_00_10_00>(even),
+                _mm_shuffle_epi32::<0b00_00_10_00>(odd),
+            )
+        }
+    }
+}
+
+/// Extracts 32-bit lane `LANE`, using `pextrd` only when SSE4.1 is available.
+#[inline(always)]
+unsafe fn _mm_extract_epi32x<const SSE4_1: bool, const LANE: i32>(v: __m128i) -> u32 {
+    unsafe {
+        if SSE4_1 {
+            _mm_extract_epi32::<LANE>(v) as u32
+        } else {
+            _mm_cvtsi128_si32(_mm_shuffle_epi32::<LANE>(v)) as u32
+        }
+    }
+}
+
+#[target_feature(enable = "sse2")]
+pub(crate) unsafe fn sse2_murmurhash3_32(bytes: &[u8], seed: u32) -> u32 {
+    let h1 = unsafe { sse2_murmurhash3_32_blocks(bytes, seed) };
+    murmurhash3_32_finalize(bytes.chunks_exact(4).remainder(), h1, bytes.len())
 }
 
 #[target_feature(enable = "sse2")]
-pub(crate) unsafe fn sse_murmurhash3_32(bytes: &[u8], seed: u32) -> u32 {
+pub(crate) unsafe fn sse2_murmurhash3_32_blocks(bytes: &[u8], h1: u32) -> u32 {
+    unsafe { murmurhash3_32_blocks_impl::<false>(bytes, h1) }
+}
+
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn sse41_murmurhash3_32(bytes: &[u8], seed: u32) -> u32 {
+    let h1 = unsafe { sse41_murmurhash3_32_blocks(bytes, seed) };
+    murmurhash3_32_finalize(bytes.chunks_exact(4).remainder(), h1, bytes.len())
+}
+
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn sse41_murmurhash3_32_blocks(bytes: &[u8], h1: u32) -> u32 {
+    unsafe { murmurhash3_32_blocks_impl::<true>(bytes, h1) }
+}
+
+#[inline(always)]
+unsafe fn murmurhash3_32_blocks_impl<const SSE4_1: bool>(bytes: &[u8], h1: u32) -> u32 {
     const C1: u32 = 0xcc9e2d51u32;
     const C2: u32 = 0x1b873593u32;
 
-    let read_size = 4;
-    let len = bytes.len() as u32;
-    let block_count = len / read_size;
-
-    let mut h1 = seed;
+    let mut h1 = h1;
 
-    let c1 = _mm_set1_epi32(C1 as i32);
-    let c2 = _mm_set1_epi32(C2 as i32);
+    let (c1, c2) = unsafe { (_mm_set1_epi32(C1 as i32), _mm_set1_epi32(C2 as i32)) };
 
     for chunk in bytes.chunks_exact(64) {
         unsafe {
@@ -61,97 +111,97 @@ pub(crate) unsafe fn sse_murmurhash3_32(bytes: &[u8], seed: u32) -> u32 {
             let mut k3 = _mm_loadu_si128(chunk.get_unchecked(32..).as_ptr().cast());
             let mut k4 = _mm_loadu_si128(chunk.get_unchecked(48..).as_ptr().cast());
 
-            k1 = _mm_mullo_epi32(k1, c1);
-            k2 = _mm_mullo_epi32(k2, c1);
-            k3 = _mm_mullo_epi32(k3, c1);
-            k4 = _mm_mullo_epi32(k4, c1);
+            k1 = _mm_mullo_epi32x::<SSE4_1>(k1, c1);
+            k2 = _mm_mullo_epi32x::<SSE4_1>(k2, c1);
+            k3 = _mm_mullo_epi32x::<SSE4_1>(k3, c1);
+            k4 = _mm_mullo_epi32x::<SSE4_1>(k4, c1);
 
             k1 = _mm_rotate_left15(k1);
             k2 = _mm_rotate_left15(k2);
             k3 = _mm_rotate_left15(k3);
             k4 = _mm_rotate_left15(k4);
 
-            k1 = _mm_mullo_epi32(k1, c2);
-            k2 = _mm_mullo_epi32(k2, c2);
-            k3 = _mm_mullo_epi32(k3, c2);
-            k4 = _mm_mullo_epi32(k4, c2);
+            k1 = _mm_mullo_epi32x::<SSE4_1>(k1, c2);
+            k2 = _mm_mullo_epi32x::<SSE4_1>(k2, c2);
+            k3 = _mm_mullo_epi32x::<SSE4_1>(k3, c2);
+            k4 = _mm_mullo_epi32x::<SSE4_1>(k4, c2);
 
-            h1 ^= _mm_extract_epi32::<0>(k1) as u32;
+            h1 ^= _mm_extract_epi32x::<SSE4_1, 0>(k1);
             h1 = h1.rotate_left(13);
             h1 = h1.wrapping_mul(5);
             h1 = h1.wrapping_add(0xe6546b64);
 
-            h1 ^= _mm_extract_epi32::<1>(k1) as u32;
+            h1 ^= _mm_extract_epi32x::<SSE4_1, 1>(k1);
             h1 = h1.rotate_left(13);
             h1 = h1.wrapping_mul(5);
             h1 = h1.wrapping_add(0xe6546b64);
 
-            h1 ^= _mm_extract_epi32::<2>(k1) as u32;
+            h1 ^= _mm_extract_epi32x::<SSE4_1, 2>(k1);
             h1 = h1.rotate_left(13);
             h1 = h1.wrapping_mul(5);
             h1 = h1.wrapping_add(0xe6546b64);
 
-            h1 ^= _mm_extract_epi32::<3>(k1) as u32;
+            h1 ^= _mm_extract_epi32x::<SSE4_1, 3>(k1);
             h1 = h1.rotate_left(13);
             h1 = h1.wrapping_mul(5);
             h1 = h1.wrapping_add(0xe6546b64);
 
-            h1 ^= _mm_extract_epi32::<0>(k2) as u32;
+            h1 ^= _mm_extract_epi32x::<SSE4_1, 0>(k2);
             h1 = h1.rotate_left(13);
             h1 = h1.wrapping_mul(5);
             h1 = h1.wrapping_add(0xe6546b64);
 
-            h1 ^= _mm_extract_epi32::<1>(k2) as u32;
+            h1 ^= _mm_extract_epi32x::<SSE4_1, 1>(k2);
             h1 = h1.rotate_left(13);
             h1 = h1.wrapping_mul(5);
             h1 = h1.wrapping_add(0xe6546b64);
 
-            h1 ^= _mm_extract_epi32::<2>(k2) as u32;
+            h1 ^= _mm_extract_epi32x::<SSE4_1, 2>(k2);
             h1 = h1.rotate_left(13);
             h1 = h1.wrapping_mul(5);
             h1 = h1.wrapping_add(0xe6546b64);
 
-            h1 ^= _mm_extract_epi32::<3>(k2) as u32;
+            h1 ^= _mm_extract_epi32x::<SSE4_1, 3>(k2);
             h1 = h1.rotate_left(13);
             h1 = h1.wrapping_mul(5);
             h1 = h1.wrapping_add(0xe6546b64);
 
-            h1 ^= _mm_extract_epi32::<0>(k3) as u32;
+            h1 ^= _mm_extract_epi32x::<SSE4_1, 0>(k3);
             h1 = h1.rotate_left(13);
             h1 = h1.wrapping_mul(5);
             h1 = h1.wrapping_add(0xe6546b64);
 
-            h1 ^= _mm_extract_epi32::<1>(k3) as u32;
+            h1 ^= _mm_extract_epi32x::<SSE4_1, 1>(k3);
             h1 = h1.rotate_left(13);
             h1 = h1.wrapping_mul(5);
             h1 = h1.wrapping_add(0xe6546b64);
 
-            h1 ^= _mm_extract_epi32::<2>(k3) as u32;
+            h1 ^= _mm_extract_epi32x::<SSE4_1, 2>(k3);
             h1 = h1.rotate_left(13);
             h1 = h1.wrapping_mul(5);
             h1 = h1.wrapping_add(0xe6546b64);
 
-            h1 ^= _mm_extract_epi32::<3>(k3) as u32;
+            h1 ^= _mm_extract_epi32x::<SSE4_1, 3>(k3);
             h1 = h1.rotate_left(13);
             h1 = h1.wrapping_mul(5);
             h1 = h1.wrapping_add(0xe6546b64);
 
-            h1 ^= _mm_extract_epi32::<0>(k4) as u32;
+            h1 ^= _mm_extract_epi32x::<SSE4_1, 0>(k4);
             h1 = h1.rotate_left(13);
             h1 = h1.wrapping_mul(5);
             h1 = h1.wrapping_add(0xe6546b64);
 
-            h1 ^= _mm_extract_epi32::<1>(k4) as u32;
+            h1 ^= _mm_extract_epi32x::<SSE4_1, 1>(k4);
             h1 = h1.rotate_left(13);
             h1 = h1.wrapping_mul(5);
             h1 = h1.wrapping_add(0xe6546b64);
 
-            h1 ^= _mm_extract_epi32::<2>(k4) as u32;
+            h1 ^= _mm_extract_epi32x::<SSE4_1, 2>(k4);
             h1 = h1.rotate_left(13);
             h1 = h1.wrapping_mul(5);
             h1 = h1.wrapping_add(0xe6546b64);
 
-            h1 ^= _mm_extract_epi32::<3>(k4) as u32;
+            h1 ^= _mm_extract_epi32x::<SSE4_1, 3>(k4);
             h1 = h1.rotate_left(13);
             h1 = h1.wrapping_mul(5);
             h1 = h1.wrapping_add(0xe6546b64);
@@ -165,51 +215,51 @@ pub(crate) unsafe fn sse_murmurhash3_32(bytes: &[u8], seed: u32) -> u32 {
             let mut k1 = _mm_loadu_si128(chunk.as_ptr().cast());
             let mut k2 = _mm_loadu_si128(chunk.get_unchecked(16..).as_ptr().cast());
 
-            k1 = _mm_mullo_epi32(k1, c1);
-            k2 = _mm_mullo_epi32(k2, c1);
+            k1 = _mm_mullo_epi32x::<SSE4_1>(k1, c1);
+            k2 = _mm_mullo_epi32x::<SSE4_1>(k2, c1);
 
             k1 = _mm_rotate_left15(k1);
             k2 = _mm_rotate_left15(k2);
 
-            k1 = _mm_mullo_epi32(k1, c2);
-            k2 = _mm_mullo_epi32(k2, c2);
+            k1 = _mm_mullo_epi32x::<SSE4_1>(k1, c2);
+            k2 = _mm_mullo_epi32x::<SSE4_1>(k2, c2);
 
-            h1 ^= _mm_extract_epi32::<0>(k1) as u32;
+            h1 ^= _mm_extract_epi32x::<SSE4_1, 0>(k1);
             h1 = h1.rotate_left(13);
             h1 = h1.wrapping_mul(5);
             h1 = h1.wrapping_add(0xe6546b64);
 
-            h1 ^= _mm_extract_epi32::<1>(k1) as u32;
+            h1 ^= _mm_extract_epi32x::<SSE4_1, 1>(k1);
             h1 = h1.rotate_left(13);
             h1 = h1.wrapping_mul(5);
             h1 = h1.wrapping_add(0xe6546b64);
 
-            h1 ^= _mm_extract_epi32::<2>(k1) as u32;
+            h1 ^= _mm_extract_epi32x::<SSE4_1, 2>(k1);
             h1 = h1.rotate_left(13);
             h1 = h1.wrapping_mul(5);
             h1 = h1.wrapping_add(0xe6546b64);
 
-            h1 ^= _mm_extract_epi32::<3>(k1) as u32;
+            h1 ^= _mm_extract_epi32x::<SSE4_1, 3>(k1);
             h1 = h1.rotate_left(13);
             h1 = h1.wrapping_mul(5);
             h1 = h1.wrapping_add(0xe6546b64);
 
-            h1 ^= _mm_extract_epi32::<0>(k2) as u32;
+            h1 ^= _mm_extract_epi32x::<SSE4_1, 0>(k2);
             h1 = h1.rotate_left(13);
             h1 = h1.wrapping_mul(5);
             h1 = h1.wrapping_add(0xe6546b64);
 
-            h1 ^= _mm_extract_epi32::<1>(k2) as u32;
+            h1 ^= _mm_extract_epi32x::<SSE4_1, 1>(k2);
             h1 = h1.rotate_left(13);
             h1 = h1.wrapping_mul(5);
             h1 = h1.wrapping_add(0xe6546b64);
 
-            h1 ^= _mm_extract_epi32::<2>(k2) as u32;
+            h1 ^= _mm_extract_epi32x::<SSE4_1, 2>(k2);
             h1 = h1.rotate_left(13);
             h1 = h1.wrapping_mul(5);
             h1 = h1.wrapping_add(0xe6546b64);
 
-            h1 ^= _mm_extract_epi32::<3>(k2) as u32;
+            h1 ^= _mm_extract_epi32x::<SSE4_1, 3>(k2);
             h1 = h1.rotate_left(13);
             h1 = h1.wrapping_mul(5);
             h1 = h1.wrapping_add(0xe6546b64);
@@ -219,7 +269,7 @@ pub(crate) unsafe fn sse_murmurhash3_32(bytes: &[u8], seed: u32) -> u32 {
     let rem = rem.chunks_exact(32).remainder();
 
     for chunk in rem.chunks_exact(4) {
-        let mut k1 = u32::from_ne_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]).to_le();
+        let mut k1 = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
 
         k1 = k1.wrapping_mul(C1);
         k1 = k1.rotate_left(15);
@@ -231,37 +281,19 @@ pub(crate) unsafe fn sse_murmurhash3_32(bytes: &[u8], seed: u32) -> u32 {
         h1 = h1.wrapping_add(0xe6546b64)
     }
 
-    let mut k1 = 0u32;
-
-    if len & 3 == 3 {
-        k1 ^= (bytes[(block_count * read_size) as usize + 2] as u32) << 16;
-    }
-    if len & 3 >= 2 {
-        k1 ^= (bytes[(block_count * read_size) as usize + 1] as u32) << 8;
-    }
-    if len & 3 >= 1 {
-        k1 ^= bytes[(block_count * read_size) as usize] as u32;
-        k1 = k1.wrapping_mul(C1);
-        k1 = k1.rotate_left(15);
-        k1 = k1.wrapping_mul(C2);
-        h1 ^= k1;
-    }
-
-    h1 ^= bytes.len() as u32;
-    h1 = scramble(h1);
-
     h1
 }
 
 #[cfg(test)]
 mod test {
-    use super::sse_murmurhash3_32;
+    use super::{sse2_murmurhash3_32, sse41_murmurhash3_32};
+    use crate::generic::murmurhash3_32;
 
     #[test]
     fn test_empty_string() {
         if std::arch::is_x86_feature_detected!("sse2") {
             unsafe {
-                assert_eq!(sse_murmurhash3_32("".as_bytes(), 0), 0);
+                assert_eq!(sse2_murmurhash3_32("".as_bytes(), 0), 0);
             }
         }
     }
@@ -270,10 +302,10 @@ mod test {
     fn test_tail_lengths() {
         if std::arch::is_x86_feature_detected!("sse2") {
             unsafe {
-                assert_eq!(sse_murmurhash3_32("1".as_bytes(), 0), 2484513939);
-                assert_eq!(sse_murmurhash3_32("12".as_bytes(), 0), 4191350549);
-                assert_eq!(sse_murmurhash3_32("123".as_bytes(), 0), 2662625771);
-                assert_eq!(sse_murmurhash3_32("1234".as_bytes(), 0), 1914461635);
+                assert_eq!(sse2_murmurhash3_32("1".as_bytes(), 0), 2484513939);
+                assert_eq!(sse2_murmurhash3_32("12".as_bytes(), 0), 4191350549);
+                assert_eq!(sse2_murmurhash3_32("123".as_bytes(), 0), 2662625771);
+                assert_eq!(sse2_murmurhash3_32("1234".as_bytes(), 0), 1914461635);
             }
         }
     }
@@ -283,11 +315,14 @@ mod test {
         if std::arch::is_x86_feature_detected!("sse2") {
             unsafe {
                 assert_eq!(
-                    sse_murmurhash3_32("The quick brown fox jumps over the lazy dog".as_bytes(), 0),
+                    sse2_murmurhash3_32(
+                        "The quick brown fox jumps over the lazy dog".as_bytes(),
+                        0
+                    ),
                     776992547
                 );
                 assert_eq!(
-                    sse_murmurhash3_32(
+                    sse2_murmurhash3_32(
                         "Rust high performace utilities for YUV format handling and conversion."
                             .as_bytes(),
                         0
@@ -295,10 +330,10 @@ mod test {
                     937425919
                 );
                 assert_eq!(
-                    sse_murmurhash3_32("432432 gfdsafgsd 32432 fds".as_bytes(), 0),
+                    sse2_murmurhash3_32("432432 gfdsafgsd 32432 fds".as_bytes(), 0),
                     948823384
                 );
-                assert_eq!(sse_murmurhash3_32("MurmurHash2 (32-bit, x86)—The original version; contains a flaw that weakens collision in some cases.[9]
+                assert_eq!(sse2_murmurhash3_32("MurmurHash2 (32-bit, x86)—The original version; contains a flaw that weakens collision in some cases.[9]
 MurmurHash2A (32-bit, x86)—A fixed variant using Merkle–Damgård construction. Slightly slower.
 CMurmurHash2A (32-bit, x86)—MurmurHash2A, but works incrementally.
 MurmurHashNeutral2 (32-bit, x86)—Slower, but endian- and alignment-neutral.
@@ -309,4 +344,32 @@ MurmurHash64B (64-bit, x86)—A 64-bit version optimized for 32-bit platforms. I
             }
         }
     }
+
+    #[test]
+    fn test_sse41_matches_generic() {
+        if std::arch::is_x86_feature_detected!("sse4.1") {
+            unsafe {
+                assert_eq!(sse41_murmurhash3_32("".as_bytes(), 0), 0);
+                assert_eq!(sse41_murmurhash3_32("123".as_bytes(), 0), 2662625771);
+                assert_eq!(
+                    sse41_murmurhash3_32(
+                        "The quick brown fox jumps over the lazy dog".as_bytes(),
+                        0
+                    ),
+                    776992547
+                );
+                let data: Vec<u8> = (0..300u32).map(|x| (x * 23 + 9) as u8).collect();
+                for len in 0..data.len() {
+                    assert_eq!(
+                        sse41_murmurhash3_32(&data[..len], len as u32),
+                        murmurhash3_32(&data[..len], len as u32)
+                    );
+                    assert_eq!(
+                        sse2_murmurhash3_32(&data[..len], len as u32),
+                        murmurhash3_32(&data[..len], len as u32)
+                    );
+                }
+            }
+        }
+    }
 }
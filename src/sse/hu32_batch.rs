@@ -0,0 +1,185 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 10/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use super::hu32::_mm_mullo_epi32x;
+use crate::batch::{
+    hash_lane_groups, lane_active, lane_blocks, lane_lens, lane_tails, lane_words,
+    lane_words_unchecked,
+};
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+#[inline(always)]
+unsafe fn _mm_rotl_epi32<const L: i32, const R: i32>(v: __m128i) -> __m128i {
+    unsafe { _mm_or_si128(_mm_slli_epi32::<L>(v), _mm_srli_epi32::<R>(v)) }
+}
+
+#[inline(always)]
+unsafe fn _mm_loadu_lanes(v: &[u32; 4]) -> __m128i {
+    unsafe { _mm_loadu_si128(v.as_ptr().cast()) }
+}
+
+#[inline(always)]
+unsafe fn _mm_mix_k1<const SSE4_1: bool>(k1: __m128i, c1: __m128i, c2: __m128i) -> __m128i {
+    unsafe {
+        let k1 = _mm_mullo_epi32x::<SSE4_1>(k1, c1);
+        let k1 = _mm_rotl_epi32::<15, 17>(k1);
+        _mm_mullo_epi32x::<SSE4_1>(k1, c2)
+    }
+}
+
+#[inline(always)]
+unsafe fn _mm_mix_h1(h1: __m128i, k1: __m128i) -> __m128i {
+    unsafe {
+        let h1 = _mm_xor_si128(h1, k1);
+        let h1 = _mm_rotl_epi32::<13, 19>(h1);
+        // h1 * 5
+        let h1 = _mm_add_epi32(_mm_slli_epi32::<2>(h1), h1);
+        _mm_add_epi32(h1, _mm_set1_epi32(0xe6546b64u32 as i32))
+    }
+}
+
+#[inline(always)]
+unsafe fn murmurhash3_32_batch4_impl<const SSE4_1: bool>(keys: &[&[u8]; 4], seed: u32) -> [u32; 4] {
+    const C1: u32 = 0xcc9e2d51u32;
+    const C2: u32 = 0x1b873593u32;
+
+    unsafe {
+        let c1 = _mm_set1_epi32(C1 as i32);
+        let c2 = _mm_set1_epi32(C2 as i32);
+
+        let blocks = lane_blocks(keys);
+        let min_blocks = blocks.iter().copied().min().unwrap_or(0);
+        let max_blocks = blocks.iter().copied().max().unwrap_or(0);
+
+        let mut h1 = _mm_set1_epi32(seed as i32);
+
+        for i in 0..min_blocks {
+            let k1 = _mm_loadu_lanes(&lane_words_unchecked(keys, i));
+            h1 = _mm_mix_h1(h1, _mm_mix_k1::<SSE4_1>(k1, c1, c2));
+        }
+
+        for i in min_blocks..max_blocks {
+            let active = _mm_loadu_lanes(&lane_active(&blocks, i));
+            let k1 = _mm_loadu_lanes(&lane_words(keys, i));
+            let mixed = _mm_mix_h1(h1, _mm_mix_k1::<SSE4_1>(k1, c1, c2));
+            h1 = _mm_or_si128(_mm_and_si128(active, mixed), _mm_andnot_si128(active, h1));
+        }
+
+        // A zero tail word mixes to zero, so lanes without a tail are untouched.
+        let tail = _mm_loadu_lanes(&lane_tails(keys));
+        h1 = _mm_xor_si128(h1, _mm_mix_k1::<SSE4_1>(tail, c1, c2));
+
+        h1 = _mm_xor_si128(h1, _mm_loadu_lanes(&lane_lens(keys)));
+
+        h1 = _mm_xor_si128(h1, _mm_srli_epi32::<16>(h1));
+        h1 = _mm_mullo_epi32x::<SSE4_1>(h1, _mm_set1_epi32(0x85ebca6bu32 as i32));
+        h1 = _mm_xor_si128(h1, _mm_srli_epi32::<13>(h1));
+        h1 = _mm_mullo_epi32x::<SSE4_1>(h1, _mm_set1_epi32(0xc2b2ae35u32 as i32));
+        h1 = _mm_xor_si128(h1, _mm_srli_epi32::<16>(h1));
+
+        let mut out = [0u32; 4];
+        _mm_storeu_si128(out.as_mut_ptr().cast(), h1);
+        out
+    }
+}
+
+#[target_feature(enable = "sse2")]
+unsafe fn sse2_murmurhash3_32_batch4(keys: &[&[u8]; 4], seed: u32) -> [u32; 4] {
+    unsafe { murmurhash3_32_batch4_impl::<false>(keys, seed) }
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn sse41_murmurhash3_32_batch4(keys: &[&[u8]; 4], seed: u32) -> [u32; 4] {
+    unsafe { murmurhash3_32_batch4_impl::<true>(keys, seed) }
+}
+
+#[target_feature(enable = "sse2")]
+pub(crate) unsafe fn sse2_murmurhash3_32_batch(keys: &[&[u8]], seed: u32, out: &mut [u32]) {
+    hash_lane_groups(keys.iter().copied(), out, |group| unsafe {
+        sse2_murmurhash3_32_batch4(group, seed)
+    });
+}
+
+#[target_feature(enable = "sse2")]
+pub(crate) unsafe fn sse2_murmurhash3_32_batch_fixed(
+    keys: &[u8],
+    key_len: usize,
+    seed: u32,
+    out: &mut [u32],
+) {
+    hash_lane_groups(keys.chunks_exact(key_len), out, |group| unsafe {
+        sse2_murmurhash3_32_batch4(group, seed)
+    });
+}
+
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn sse41_murmurhash3_32_batch(keys: &[&[u8]], seed: u32, out: &mut [u32]) {
+    hash_lane_groups(keys.iter().copied(), out, |group| unsafe {
+        sse41_murmurhash3_32_batch4(group, seed)
+    });
+}
+
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn sse41_murmurhash3_32_batch_fixed(
+    keys: &[u8],
+    key_len: usize,
+    seed: u32,
+    out: &mut [u32],
+) {
+    hash_lane_groups(keys.chunks_exact(key_len), out, |group| unsafe {
+        sse41_murmurhash3_32_batch4(group, seed)
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::{sse2_murmurhash3_32_batch, sse41_murmurhash3_32_batch};
+    use crate::generic::murmurhash3_32;
+
+    #[test]
+    fn test_matches_generic() {
+        let owned: Vec<Vec<u8>> = (0..131u32)
+            .map(|i| (0..(i * 5) % 67).map(|x| (x * 17 + i) as u8).collect())
+            .collect();
+        let keys: Vec<&[u8]> = owned.iter().map(|k| k.as_slice()).collect();
+        let expected: Vec<u32> = keys.iter().map(|k| murmurhash3_32(k, 7)).collect();
+
+        let mut out = vec![0u32; keys.len()];
+        if std::arch::is_x86_feature_detected!("sse2") {
+            unsafe { sse2_murmurhash3_32_batch(&keys, 7, &mut out) };
+            assert_eq!(out, expected);
+        }
+        if std::arch::is_x86_feature_detected!("sse4.1") {
+            unsafe { sse41_murmurhash3_32_batch(&keys, 7, &mut out) };
+            assert_eq!(out, expected);
+        }
+    }
+}
@@ -26,6 +26,18 @@
  * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
+mod h2u32;
+mod hu128;
 mod hu32;
+mod hu32_batch;
 
-pub(crate) use hu32::sse_murmurhash3_32;
+pub(crate) use h2u32::sse_murmurhash2_32_blocks;
+pub(crate) use hu32::{
+    sse2_murmurhash3_32, sse2_murmurhash3_32_blocks, sse41_murmurhash3_32,
+    sse41_murmurhash3_32_blocks,
+};
+pub(crate) use hu32_batch::{
+    sse2_murmurhash3_32_batch, sse2_murmurhash3_32_batch_fixed, sse41_murmurhash3_32_batch,
+    sse41_murmurhash3_32_batch_fixed,
+};
+pub(crate) use hu128::sse_murmurhash3_x86_128_blocks;
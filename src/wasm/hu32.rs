@@ -0,0 +1,174 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 10/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::generic::murmurhash3_32_finalize;
+use std::arch::wasm32::*;
+
+#[inline(always)]
+fn v128_rotate_left15(v: v128) -> v128 {
+    v128_or(i32x4_shl(v, 15), u32x4_shr(v, 17))
+}
+
+#[inline(always)]
+fn v128_mix_k1(k1: v128, c1: v128, c2: v128) -> v128 {
+    let k1 = i32x4_mul(k1, c1);
+    let k1 = v128_rotate_left15(k1);
+    i32x4_mul(k1, c2)
+}
+
+#[inline(always)]
+fn fold_h1(mut h1: u32, k1: v128) -> u32 {
+    h1 ^= u32x4_extract_lane::<0>(k1);
+    h1 = h1.rotate_left(13);
+    h1 = h1.wrapping_mul(5);
+    h1 = h1.wrapping_add(0xe6546b64);
+
+    h1 ^= u32x4_extract_lane::<1>(k1);
+    h1 = h1.rotate_left(13);
+    h1 = h1.wrapping_mul(5);
+    h1 = h1.wrapping_add(0xe6546b64);
+
+    h1 ^= u32x4_extract_lane::<2>(k1);
+    h1 = h1.rotate_left(13);
+    h1 = h1.wrapping_mul(5);
+    h1 = h1.wrapping_add(0xe6546b64);
+
+    h1 ^= u32x4_extract_lane::<3>(k1);
+    h1 = h1.rotate_left(13);
+    h1 = h1.wrapping_mul(5);
+    h1.wrapping_add(0xe6546b64)
+}
+
+pub(crate) fn wasm_murmurhash3_32(bytes: &[u8], seed: u32) -> u32 {
+    let h1 = wasm_murmurhash3_32_blocks(bytes, seed);
+    murmurhash3_32_finalize(bytes.chunks_exact(4).remainder(), h1, bytes.len())
+}
+
+pub(crate) fn wasm_murmurhash3_32_blocks(bytes: &[u8], h1: u32) -> u32 {
+    const C1: u32 = 0xcc9e2d51u32;
+    const C2: u32 = 0x1b873593u32;
+
+    let mut h1 = h1;
+
+    let c1 = u32x4_splat(C1);
+    let c2 = u32x4_splat(C2);
+
+    for chunk in bytes.chunks_exact(64) {
+        unsafe {
+            let mut k1 = v128_load(chunk.as_ptr() as *const v128);
+            let mut k2 = v128_load(chunk.get_unchecked(16..).as_ptr() as *const v128);
+            let mut k3 = v128_load(chunk.get_unchecked(32..).as_ptr() as *const v128);
+            let mut k4 = v128_load(chunk.get_unchecked(48..).as_ptr() as *const v128);
+
+            k1 = v128_mix_k1(k1, c1, c2);
+            k2 = v128_mix_k1(k2, c1, c2);
+            k3 = v128_mix_k1(k3, c1, c2);
+            k4 = v128_mix_k1(k4, c1, c2);
+
+            h1 = fold_h1(h1, k1);
+            h1 = fold_h1(h1, k2);
+            h1 = fold_h1(h1, k3);
+            h1 = fold_h1(h1, k4);
+        }
+    }
+
+    let rem = bytes.chunks_exact(64).remainder();
+
+    for chunk in rem.chunks_exact(16) {
+        unsafe {
+            let k1 = v128_load(chunk.as_ptr() as *const v128);
+            h1 = fold_h1(h1, v128_mix_k1(k1, c1, c2));
+        }
+    }
+
+    let rem = rem.chunks_exact(16).remainder();
+
+    for chunk in rem.chunks_exact(4) {
+        let mut k1 = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+
+        k1 = k1.wrapping_mul(C1);
+        k1 = k1.rotate_left(15);
+        k1 = k1.wrapping_mul(C2);
+
+        h1 ^= k1;
+        h1 = h1.rotate_left(13);
+        h1 = h1.wrapping_mul(5);
+        h1 = h1.wrapping_add(0xe6546b64)
+    }
+
+    h1
+}
+
+#[cfg(test)]
+mod test {
+    use super::wasm_murmurhash3_32;
+    use crate::generic::murmurhash3_32;
+
+    #[test]
+    fn test_empty_string() {
+        assert_eq!(wasm_murmurhash3_32("".as_bytes(), 0), 0);
+    }
+
+    #[test]
+    fn test_tail_lengths() {
+        assert_eq!(wasm_murmurhash3_32("1".as_bytes(), 0), 2484513939);
+        assert_eq!(wasm_murmurhash3_32("12".as_bytes(), 0), 4191350549);
+        assert_eq!(wasm_murmurhash3_32("123".as_bytes(), 0), 2662625771);
+        assert_eq!(wasm_murmurhash3_32("1234".as_bytes(), 0), 1914461635);
+    }
+
+    #[test]
+    fn test_large_data() {
+        assert_eq!(
+            wasm_murmurhash3_32("The quick brown fox jumps over the lazy dog".as_bytes(), 0),
+            776992547
+        );
+        assert_eq!(
+            wasm_murmurhash3_32(
+                "Rust high performace utilities for YUV format handling and conversion.".as_bytes(),
+                0
+            ),
+            937425919
+        );
+        assert_eq!(
+            wasm_murmurhash3_32("432432 gfdsafgsd 32432 fds".as_bytes(), 0),
+            948823384
+        );
+    }
+
+    #[test]
+    fn test_matches_generic() {
+        let data: Vec<u8> = (0..300u32).map(|x| (x * 23 + 9) as u8).collect();
+        for len in 0..data.len() {
+            assert_eq!(
+                wasm_murmurhash3_32(&data[..len], len as u32),
+                murmurhash3_32(&data[..len], len as u32)
+            );
+        }
+    }
+}
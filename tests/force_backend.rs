@@ -0,0 +1,57 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk 10/2025. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+//! `force_backend` changes process-wide state, so it is tested in its own
+//! binary where no other test can observe the pinned backend.
+
+use mm3h::{Backend, Murmur3Hasher, Unsupported, active_backend, force_backend};
+use std::hash::Hasher;
+
+#[test]
+fn force_backend_pins_every_dispatcher() {
+    let detected = active_backend();
+    let data: Vec<u8> = (0..200u32).map(|x| (x * 3 + 1) as u8).collect();
+    let expected = mm3h::murmurhash3_32_with_seed(&data, 5);
+
+    assert_eq!(force_backend(Backend::Generic), Ok(()));
+    assert_eq!(active_backend(), Backend::Generic);
+    assert_eq!(mm3h::murmurhash3_32_with_seed(&data, 5), expected);
+    let mut hasher = Murmur3Hasher::new_with_seed(5);
+    hasher.write(&data);
+    assert_eq!(hasher.finish_32(), expected);
+
+    for backend in Backend::ALL.into_iter().filter(|b| !b.is_supported()) {
+        assert_eq!(force_backend(backend), Err(Unsupported { backend }));
+        assert_eq!(active_backend(), Backend::Generic);
+    }
+
+    assert_eq!(force_backend(detected), Ok(()));
+    assert_eq!(active_backend(), detected);
+    assert_eq!(mm3h::murmurhash3_32_with_seed(&data, 5), expected);
+}